@@ -0,0 +1,93 @@
+use gd_core::types::str::Str;
+use gd_internals::{
+    http::RequestError,
+    robtop::{self, Map},
+};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{
+    client::Client,
+    endpoints,
+    pages::{self, Page},
+    secrets,
+    state::State,
+    utils::owned,
+};
+
+pub const DELIMITER: &str = ":";
+
+pub const NO_RESULTS: &str = "-1";
+
+pub(crate) mod keys {
+    pub const NAME: &str = "4";
+    pub const YOUTUBE: &str = "7";
+}
+
+// top artists do not include ids, so they are not `Artist` entities
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TopArtist<'a> {
+    pub name: Str<'a>,
+    pub youtube: Option<Str<'a>>,
+}
+
+#[derive(Debug, Error)]
+pub enum TopArtistsError {
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Serialize)]
+struct TopArtistsForm<'f> {
+    page: u32,
+    secret: &'f str,
+}
+
+pub(crate) fn parse_top_artist(string: &str) -> TopArtist<'static> {
+    let map = Map::parse(string, DELIMITER);
+
+    TopArtist {
+        name: owned(map.get(keys::NAME).unwrap_or_default()),
+        youtube: map.get(keys::YOUTUBE).map(owned),
+    }
+}
+
+impl<S: State> Client<S> {
+    pub async fn top_artists(
+        &self,
+        page: u32,
+    ) -> Result<Page<TopArtist<'static>>, TopArtistsError> {
+        let form = TopArtistsForm {
+            page,
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::TOP_ARTISTS, &form)
+            .await?;
+
+        let response = response.trim();
+
+        if response == NO_RESULTS {
+            return Ok(Page::empty());
+        }
+
+        let (artists, trailer) = response
+            .split_once(pages::SECTION_DELIMITER)
+            .unwrap_or((response, ""));
+
+        let artists = artists
+            .split(pages::ITEM_DELIMITER)
+            .filter(|item| !item.is_empty())
+            .map(parse_top_artist)
+            .collect();
+
+        let page = pages::parse_page(artists, trailer)?;
+
+        Ok(page)
+    }
+}
@@ -2,7 +2,9 @@ use gd_core::types::{
     id::{AccountId, UserId},
     str::Str,
 };
+use gd_crypto::{salts, sha1};
 use ownership::IntoOwned;
+use serde::Serialize;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, IntoOwned)]
 pub struct Credentials<'c> {
@@ -10,6 +12,12 @@ pub struct Credentials<'c> {
     pub password: Str<'c>,
 }
 
+impl Credentials<'_> {
+    pub fn gjp2(&self) -> String {
+        sha1::hash_with_salt(self.password.as_ref(), salts::GJP)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, IntoOwned)]
 pub struct Reference {
     pub id: UserId,
@@ -21,3 +29,19 @@ pub struct Auth<'a> {
     pub credentials: Credentials<'a>,
     pub reference: Reference,
 }
+
+impl Auth<'_> {
+    pub(crate) fn form(&self) -> AuthForm {
+        AuthForm {
+            account_id: self.reference.account_id,
+            gjp2: self.credentials.gjp2(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct AuthForm {
+    #[serde(rename = "accountID")]
+    pub(crate) account_id: AccountId,
+    pub(crate) gjp2: String,
+}
@@ -1,7 +1,15 @@
-use gd_internals::session::Session;
+use gd_core::types::id::{AccountId, UserId};
+use gd_crypto::random;
+use gd_internals::{
+    http::{RATE_LIMITED, REQUEST_ERROR, RequestError},
+    session::Session,
+};
+use serde::Serialize;
+use thiserror::Error;
 
 use crate::{
-    auth::{Auth, Credentials},
+    auth::{Auth, Credentials, Reference},
+    endpoints, secrets,
     state::{self, State},
 };
 
@@ -17,8 +25,83 @@ impl<S: State> Client<S> {
     }
 }
 
+pub const LOGIN_ERROR: &str = "failed to login";
+
+pub const FAILED: &str = "-1";
+pub const INVALID_CREDENTIALS: &str = "-11";
+pub const DISABLED: &str = "-12";
+pub const NOT_ACTIVATED: &str = "-13";
+
+pub const REFERENCE_DELIMITER: char = ',';
+
+#[derive(Debug, Error)]
+pub enum LoginReason {
+    #[error("invalid credentials")]
+    InvalidCredentials,
+    #[error("account is disabled")]
+    Disabled,
+    #[error("account is not activated")]
+    NotActivated,
+    #[error("{RATE_LIMITED}")]
+    RateLimited,
+    #[error("{REQUEST_ERROR}")]
+    Request(#[source] RequestError),
+    #[error("unexpected response `{response}`")]
+    Unexpected { response: String },
+}
+
+impl From<RequestError> for LoginReason {
+    fn from(error: RequestError) -> Self {
+        match error {
+            RequestError::RateLimited => Self::RateLimited,
+            error => Self::Request(error),
+        }
+    }
+}
+
+impl LoginReason {
+    pub fn from_response(response: &str) -> Self {
+        match response {
+            FAILED | INVALID_CREDENTIALS => Self::InvalidCredentials,
+            DISABLED => Self::Disabled,
+            NOT_ACTIVATED => Self::NotActivated,
+            _ => Self::Unexpected {
+                response: response.to_owned(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("{LOGIN_ERROR}")]
 pub struct LoginError {
     pub client: Simple,
+    #[source]
+    pub reason: LoginReason,
+}
+
+impl LoginError {
+    pub const fn new(client: Simple, reason: LoginReason) -> Self {
+        Self { client, reason }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LoginForm<'f> {
+    udid: &'f str,
+    user_name: &'f str,
+    gjp2: &'f str,
+    secret: &'f str,
+}
+
+fn parse_reference(response: &str) -> Option<Reference> {
+    let (account_id, id) = response.trim().split_once(REFERENCE_DELIMITER)?;
+
+    let account_id = AccountId::new(account_id.parse().ok()?);
+    let id = UserId::new(id.parse().ok()?);
+
+    Some(Reference { id, account_id })
 }
 
 pub type Simple = Client<state::Simple>;
@@ -32,8 +115,33 @@ impl Simple {
         Authenticated::authenticated(self.session, auth)
     }
 
-    pub async fn login<'c>(self, credentials: Credentials<'c>) -> Authenticated<'c> {
-        todo!()
+    pub async fn login<'c>(
+        self,
+        credentials: Credentials<'c>,
+    ) -> Result<Authenticated<'c>, LoginError> {
+        match self.try_login(&credentials).await {
+            Ok(reference) => Ok(self.authenticate(Auth {
+                credentials,
+                reference,
+            })),
+            Err(reason) => Err(LoginError::new(self, reason)),
+        }
+    }
+
+    async fn try_login(&self, credentials: &Credentials<'_>) -> Result<Reference, LoginReason> {
+        let udid = random::udid();
+        let gjp2 = credentials.gjp2();
+
+        let form = LoginForm {
+            udid: &udid,
+            user_name: &credentials.name,
+            gjp2: &gjp2,
+            secret: secrets::ACCOUNT,
+        };
+
+        let response = self.session.http.post(endpoints::LOGIN, &form).await?;
+
+        parse_reference(&response).ok_or_else(|| LoginReason::from_response(response.trim()))
     }
 }
 
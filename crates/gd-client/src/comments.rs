@@ -0,0 +1,537 @@
+use gd_core::types::{
+    id::{AccountId, CommentId, LevelId, UserId},
+    record::{Percent, Record},
+    statistics::Rating,
+    time::Duration,
+};
+use gd_crypto::{base64, check, keys as crypto_keys, salts};
+use gd_entities::{
+    comments::{CoreComment, LevelComment, UserComment},
+    levels::LevelReference,
+    users::UserReference,
+};
+use gd_enums::comments::{Sorting, Type};
+use gd_internals::{
+    http::RequestError,
+    robtop::{self, InvalidError, Map},
+};
+use ownership::IntoOwned;
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{
+    auth::AuthForm,
+    client::{Authenticated, Client},
+    endpoints,
+    pages::{self, Page},
+    secrets,
+    state::State,
+    utils::{owned, parse_base64, parse_color, parse_instant, seconds},
+    versions,
+};
+
+pub const DELIMITER: &str = "~";
+
+pub const AUTHOR_DELIMITER: &str = ":";
+
+pub const NO_RECORD: u8 = 0;
+
+pub const NO_RESULTS: &str = "-1";
+
+pub const DISABLED: &str = "-2";
+
+pub const BANNED: &str = "-10";
+pub const CLOSED: &str = "-12";
+pub const TOO_FAST: &str = "-15";
+
+pub const DELETED: &str = "1";
+
+pub const TEMPORARY_BAN: &str = "temp";
+
+pub const BAN_DELIMITER: char = '_';
+
+pub(crate) mod keys {
+    pub const LEVEL_ID: &str = "1";
+    pub const CONTENT: &str = "2";
+    pub const USER_ID: &str = "3";
+    pub const RATING: &str = "4";
+    pub const ID: &str = "6";
+    pub const CREATED_AT: &str = "9";
+    pub const PERCENT: &str = "10";
+    pub const COLOR: &str = "12";
+}
+
+pub(crate) mod author_keys {
+    pub const NAME: &str = "1";
+    pub const ACCOUNT_ID: &str = "16";
+}
+
+#[derive(Debug, Error)]
+pub enum GetCommentsError {
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum CommentHistoryError {
+    #[error("comment history is disabled")]
+    Disabled,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum PostCommentError {
+    #[error("banned from commenting")]
+    Banned {
+        remaining: Option<Duration>,
+        reason: Option<String>,
+    },
+    #[error("commenting too fast")]
+    TooFast,
+    #[error("comments are closed")]
+    Closed,
+    #[error("failed to post comment")]
+    Failed,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+}
+
+#[derive(Debug, Error)]
+pub enum DeleteCommentError {
+    #[error("failed to delete comment")]
+    Failed,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PostCommentForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    user_name: &'f str,
+    comment: String,
+    #[serde(rename = "levelID")]
+    level_id: LevelId,
+    percent: u8,
+    #[serde(rename = "cType")]
+    comment_type: u8,
+    chk: String,
+    game_version: u16,
+    binary_version: u16,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DeleteCommentForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    #[serde(rename = "commentID")]
+    comment_id: CommentId,
+    #[serde(rename = "levelID")]
+    level_id: LevelId,
+    #[serde(rename = "cType")]
+    comment_type: u8,
+    game_version: u16,
+    binary_version: u16,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GetLevelCommentsForm<'f> {
+    #[serde(rename = "levelID")]
+    level_id: LevelId,
+    mode: u8,
+    page: u32,
+    total: u32,
+    game_version: u16,
+    binary_version: u16,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GetProfileCommentsForm<'f> {
+    #[serde(rename = "accountID")]
+    account_id: AccountId,
+    page: u32,
+    total: u32,
+    game_version: u16,
+    binary_version: u16,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CommentHistoryForm<'f> {
+    #[serde(rename = "userID")]
+    user_id: UserId,
+    mode: u8,
+    page: u32,
+    total: u32,
+    game_version: u16,
+    binary_version: u16,
+    secret: &'f str,
+}
+
+pub(crate) fn parse_record(map: &Map<'_>) -> Result<Option<Record>, robtop::Error> {
+    let Some(value) = map.parse_optional(keys::PERCENT)? else {
+        return Ok(None);
+    };
+
+    if value == NO_RECORD {
+        return Ok(None);
+    }
+
+    let percent =
+        Percent::new(value).ok_or_else(|| InvalidError::new(keys::PERCENT, value.to_string()))?;
+
+    Ok(Some(Record::Percent(percent)))
+}
+
+pub(crate) fn parse_author(
+    string: &str,
+    id: UserId,
+) -> Result<UserReference<'static>, robtop::Error> {
+    let map = Map::parse(string, DELIMITER);
+
+    let author = UserReference::builder()
+        .id(id)
+        .name(owned(map.get(author_keys::NAME).unwrap_or_default()))
+        .account_id(AccountId::new(
+            map.parse_or_default(author_keys::ACCOUNT_ID)?,
+        ))
+        .build();
+
+    Ok(author)
+}
+
+pub(crate) fn parse_core_comment(
+    map: &Map<'_>,
+    author: UserReference<'static>,
+) -> Result<CoreComment<'static>, robtop::Error> {
+    let comment = CoreComment::builder()
+        .id(CommentId::new(map.parse_required(keys::ID)?))
+        .author(author)
+        .content(parse_base64(map, keys::CONTENT)?)
+        .color(parse_color(map, keys::COLOR)?)
+        .rating(Rating::new(map.parse_or_default(keys::RATING)?))
+        .maybe_created_at(parse_instant(map, keys::CREATED_AT))
+        .build();
+
+    Ok(comment)
+}
+
+pub(crate) fn parse_level_comment(
+    item: &str,
+    level_id: LevelId,
+) -> Result<LevelComment<'static>, robtop::Error> {
+    let (comment, author) = item.split_once(AUTHOR_DELIMITER).unwrap_or((item, ""));
+
+    let map = Map::parse(comment, DELIMITER);
+
+    let author = parse_author(author, UserId::new(map.parse_or_default(keys::USER_ID)?))?;
+
+    let level = LevelReference::builder()
+        .id(map
+            .parse_optional(keys::LEVEL_ID)?
+            .map_or(level_id, LevelId::new))
+        .name("")
+        .build();
+
+    let comment = LevelComment::builder()
+        .core(parse_core_comment(&map, author)?)
+        .level(level)
+        .maybe_record(parse_record(&map)?)
+        .build();
+
+    Ok(comment)
+}
+
+pub(crate) fn parse_level_comments(
+    string: &str,
+    level_id: LevelId,
+) -> Result<Vec<LevelComment<'static>>, robtop::Error> {
+    string
+        .split(pages::ITEM_DELIMITER)
+        .filter(|item| !item.is_empty())
+        .map(|item| parse_level_comment(item, level_id))
+        .collect()
+}
+
+pub(crate) fn parse_user_comments(
+    string: &str,
+    author: &UserReference<'static>,
+) -> Result<Vec<UserComment<'static>>, robtop::Error> {
+    string
+        .split(pages::ITEM_DELIMITER)
+        .filter(|item| !item.is_empty())
+        .map(|item| parse_core_comment(&Map::parse(item, DELIMITER), author.clone()))
+        .collect()
+}
+
+pub(crate) fn parse_ban(response: &str) -> Option<PostCommentError> {
+    let mut parts = response.splitn(3, BAN_DELIMITER);
+
+    if parts.next()? != TEMPORARY_BAN {
+        return None;
+    }
+
+    let remaining = parts.next()?.parse().ok().map(seconds);
+
+    let reason = parts
+        .next()
+        .filter(|reason| !reason.is_empty())
+        .map(str::to_owned);
+
+    Some(PostCommentError::Banned { remaining, reason })
+}
+
+pub(crate) fn post_comment_error(response: &str) -> PostCommentError {
+    match response {
+        BANNED => PostCommentError::Banned {
+            remaining: None,
+            reason: None,
+        },
+        TOO_FAST => PostCommentError::TooFast,
+        CLOSED => PostCommentError::Closed,
+        _ => parse_ban(response).unwrap_or(PostCommentError::Failed),
+    }
+}
+
+fn split_page(response: &str) -> (&str, &str) {
+    response
+        .split_once(pages::SECTION_DELIMITER)
+        .unwrap_or((response, ""))
+}
+
+impl<S: State> Client<S> {
+    pub async fn get_level_comments(
+        &self,
+        level_id: LevelId,
+        sorting: Sorting,
+        page: u32,
+    ) -> Result<Page<LevelComment<'static>>, GetCommentsError> {
+        let form = GetLevelCommentsForm {
+            level_id,
+            mode: sorting.value(),
+            page,
+            total: 0,
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::GET_LEVEL_COMMENTS, &form)
+            .await?;
+
+        let response = response.trim();
+
+        if response == NO_RESULTS {
+            return Ok(Page::empty());
+        }
+
+        let (comments, trailer) = split_page(response);
+
+        let page = pages::parse_page(parse_level_comments(comments, level_id)?, trailer)?;
+
+        Ok(page)
+    }
+
+    pub async fn get_profile_comments(
+        &self,
+        author: &UserReference<'_>,
+        page: u32,
+    ) -> Result<Page<UserComment<'static>>, GetCommentsError> {
+        let form = GetProfileCommentsForm {
+            account_id: author.account_id,
+            page,
+            total: 0,
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::GET_PROFILE_COMMENTS, &form)
+            .await?;
+
+        let response = response.trim();
+
+        if response == NO_RESULTS {
+            return Ok(Page::empty());
+        }
+
+        let (comments, trailer) = split_page(response);
+
+        let author = author.clone().into_owned();
+
+        let page = pages::parse_page(parse_user_comments(comments, &author)?, trailer)?;
+
+        Ok(page)
+    }
+
+    pub async fn get_comment_history(
+        &self,
+        user_id: UserId,
+        sorting: Sorting,
+        page: u32,
+    ) -> Result<Page<LevelComment<'static>>, CommentHistoryError> {
+        let form = CommentHistoryForm {
+            user_id,
+            mode: sorting.value(),
+            page,
+            total: 0,
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::GET_COMMENT_HISTORY, &form)
+            .await?;
+
+        let response = response.trim();
+
+        match response {
+            NO_RESULTS => return Ok(Page::empty()),
+            DISABLED => return Err(CommentHistoryError::Disabled),
+            _ => {}
+        }
+
+        let (comments, trailer) = split_page(response);
+
+        let comments = parse_level_comments(comments, LevelId::default())?;
+
+        let page = pages::parse_page(comments, trailer)?;
+
+        Ok(page)
+    }
+}
+
+impl Authenticated<'_> {
+    async fn post_comment(
+        &self,
+        content: &str,
+        comment_type: Type,
+        level_id: LevelId,
+        percent: Option<Percent>,
+    ) -> Result<CommentId, PostCommentError> {
+        let auth = &self.state.auth;
+
+        let user_name = auth.credentials.name.as_ref();
+
+        let comment = base64::encode(content);
+
+        let percent = percent.map(Percent::get).unwrap_or_default();
+        let type_value = comment_type.value();
+
+        let chk = check::of_with_salt(
+            format!("{user_name}{comment}{level_id}{percent}{type_value}"),
+            salts::COMMENT,
+            crypto_keys::COMMENT,
+        );
+
+        let form = PostCommentForm {
+            auth: auth.form(),
+            user_name,
+            comment,
+            level_id,
+            percent,
+            comment_type: type_value,
+            chk,
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            secret: secrets::COMMON,
+        };
+
+        let endpoint = match comment_type {
+            Type::User => endpoints::POST_PROFILE_COMMENT,
+            Type::Level => endpoints::POST_LEVEL_COMMENT,
+        };
+
+        let response = self.session.http.post(endpoint, &form).await?;
+
+        let response = response.trim();
+
+        response
+            .parse()
+            .map(CommentId::new)
+            .map_err(|_| post_comment_error(response))
+    }
+
+    pub async fn post_level_comment(
+        &self,
+        level_id: LevelId,
+        content: &str,
+        percent: Option<Percent>,
+    ) -> Result<CommentId, PostCommentError> {
+        self.post_comment(content, Type::Level, level_id, percent)
+            .await
+    }
+
+    pub async fn post_profile_comment(&self, content: &str) -> Result<CommentId, PostCommentError> {
+        self.post_comment(content, Type::User, LevelId::default(), None)
+            .await
+    }
+
+    async fn delete_comment(
+        &self,
+        comment_id: CommentId,
+        comment_type: Type,
+        level_id: LevelId,
+    ) -> Result<(), DeleteCommentError> {
+        let form = DeleteCommentForm {
+            auth: self.state.auth.form(),
+            comment_id,
+            level_id,
+            comment_type: comment_type.value(),
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            secret: secrets::COMMON,
+        };
+
+        let endpoint = match comment_type {
+            Type::User => endpoints::DELETE_PROFILE_COMMENT,
+            Type::Level => endpoints::DELETE_LEVEL_COMMENT,
+        };
+
+        let response = self.session.http.post(endpoint, &form).await?;
+
+        if response.trim() != DELETED {
+            return Err(DeleteCommentError::Failed);
+        }
+
+        Ok(())
+    }
+
+    pub async fn delete_level_comment(
+        &self,
+        level_id: LevelId,
+        comment_id: CommentId,
+    ) -> Result<(), DeleteCommentError> {
+        self.delete_comment(comment_id, Type::Level, level_id).await
+    }
+
+    pub async fn delete_profile_comment(
+        &self,
+        comment_id: CommentId,
+    ) -> Result<(), DeleteCommentError> {
+        self.delete_comment(comment_id, Type::User, LevelId::default())
+            .await
+    }
+}
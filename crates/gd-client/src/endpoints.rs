@@ -0,0 +1,49 @@
+pub const LOGIN: &str = "accounts/loginGJAccount.php";
+pub const GET_USER: &str = "getGJUserInfo20.php";
+pub const SEARCH_USERS: &str = "getGJUsers20.php";
+pub const SEARCH_LEVELS: &str = "getGJLevels21.php";
+pub const DOWNLOAD_LEVEL: &str = "downloadGJLevel22.php";
+pub const GET_TIMELY: &str = "getGJDailyLevel.php";
+pub const GET_LEVEL_COMMENTS: &str = "getGJComments21.php";
+pub const GET_PROFILE_COMMENTS: &str = "getGJAccountComments20.php";
+pub const GET_COMMENT_HISTORY: &str = "getGJCommentHistory.php";
+pub const POST_LEVEL_COMMENT: &str = "uploadGJComment21.php";
+pub const POST_PROFILE_COMMENT: &str = "uploadGJAccComment20.php";
+pub const DELETE_LEVEL_COMMENT: &str = "deleteGJComment20.php";
+pub const DELETE_PROFILE_COMMENT: &str = "deleteGJAccComment20.php";
+pub const GET_MESSAGES: &str = "getGJMessages20.php";
+pub const GET_MESSAGE: &str = "downloadGJMessage20.php";
+pub const SEND_MESSAGE: &str = "uploadGJMessage20.php";
+pub const DELETE_MESSAGES: &str = "deleteGJMessages20.php";
+pub const GET_FRIEND_REQUESTS: &str = "getGJFriendRequests20.php";
+pub const SEND_FRIEND_REQUEST: &str = "uploadFriendRequest20.php";
+pub const ACCEPT_FRIEND_REQUEST: &str = "acceptGJFriendRequest20.php";
+pub const DELETE_FRIEND_REQUESTS: &str = "deleteGJFriendRequests20.php";
+pub const READ_FRIEND_REQUEST: &str = "readGJFriendRequest20.php";
+pub const GET_USER_LIST: &str = "getGJUserList20.php";
+pub const REMOVE_FRIEND: &str = "removeGJFriend20.php";
+pub const BLOCK_USER: &str = "blockGJUser20.php";
+pub const UNBLOCK_USER: &str = "unblockGJUser20.php";
+pub const GET_LEADERBOARD: &str = "getGJScores20.php";
+pub const GET_LEVEL_LEADERBOARD: &str = "getGJLevelScores211.php";
+pub const GET_PLATFORMER_LEADERBOARD: &str = "getGJLevelScoresPlat.php";
+pub const LIKE: &str = "likeGJItem211.php";
+pub const GET_GAUNTLETS: &str = "getGJGauntlets21.php";
+pub const GET_MAP_PACKS: &str = "getGJMapPacks21.php";
+pub const GET_SONG: &str = "getGJSongInfo.php";
+pub const TOP_ARTISTS: &str = "getGJTopArtists.php";
+pub const UPLOAD_LEVEL: &str = "uploadGJLevel21.php";
+pub const UPDATE_LEVEL_DESCRIPTION: &str = "updateGJDesc20.php";
+pub const DELETE_LEVEL: &str = "deleteGJLevelUser20.php";
+pub const SEARCH_LISTS: &str = "getGJLevelLists.php";
+pub const UPLOAD_LIST: &str = "uploadGJLevelList.php";
+pub const DELETE_LIST: &str = "deleteGJLevelList.php";
+pub const UPDATE_PROFILE: &str = "updateGJUserScore22.php";
+pub const UPDATE_SETTINGS: &str = "updateGJAccSettings20.php";
+pub const GET_REWARDS: &str = "getGJRewards.php";
+pub const GET_QUESTS: &str = "getGJChallenges.php";
+pub const RATE_STARS: &str = "rateGJStars211.php";
+pub const SUGGEST_STARS: &str = "suggestGJStars20.php";
+pub const RATE_DEMON: &str = "rateGJDemon21.php";
+pub const REPORT_LEVEL: &str = "reportGJLevel.php";
+pub const REQUEST_MODERATOR: &str = "requestUserAccess.php";
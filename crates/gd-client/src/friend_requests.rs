@@ -0,0 +1,305 @@
+use gd_core::types::id::{AccountId, FriendRequestId, UserId};
+use gd_crypto::base64;
+use gd_entities::{friend_requests::FriendRequest, users::UserReference};
+use gd_enums::friend_requests::Type;
+use gd_internals::{
+    http::RequestError,
+    robtop::{self, Map},
+};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{
+    auth::AuthForm,
+    client::Authenticated,
+    endpoints,
+    pages::{self, Page},
+    secrets,
+    utils::{flag, owned, parse_base64, parse_instant},
+};
+
+pub const DELIMITER: &str = ":";
+
+pub const LIST_DELIMITER: &str = ",";
+
+pub const NO_RESULTS: &str = "-2";
+
+pub const SUCCESS: &str = "1";
+
+pub const CLOSED: &str = "-1";
+
+pub(crate) mod keys {
+    pub const NAME: &str = "1";
+    pub const USER_ID: &str = "2";
+    pub const ACCOUNT_ID: &str = "16";
+    pub const ID: &str = "32";
+    pub const CONTENT: &str = "35";
+    pub const CREATED_AT: &str = "37";
+    pub const NEW: &str = "41";
+}
+
+#[derive(Debug, Error)]
+pub enum GetFriendRequestsError {
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SendFriendRequestError {
+    #[error("friend requests are closed")]
+    Closed,
+    #[error("failed to send friend request: `{response}`")]
+    Failed { response: String },
+    #[error(transparent)]
+    Request(#[from] RequestError),
+}
+
+#[derive(Debug, Error)]
+pub enum FriendRequestError {
+    #[error("friend request operation failed")]
+    Failed,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GetFriendRequestsForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    page: u32,
+    total: u32,
+    get_sent: Option<u8>,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SendFriendRequestForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    #[serde(rename = "toAccountID")]
+    to_account_id: AccountId,
+    comment: String,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AcceptFriendRequestForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    #[serde(rename = "targetAccountID")]
+    target_account_id: AccountId,
+    #[serde(rename = "requestID")]
+    request_id: FriendRequestId,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DeleteFriendRequestsForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    accounts: String,
+    is_sender: Option<u8>,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReadFriendRequestForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    #[serde(rename = "requestID")]
+    request_id: FriendRequestId,
+    secret: &'f str,
+}
+
+pub(crate) fn parse_friend_request(
+    string: &str,
+    request_type: Type,
+) -> Result<FriendRequest<'static>, robtop::Error> {
+    let map = Map::parse(string, DELIMITER);
+
+    let user = UserReference::builder()
+        .id(UserId::new(map.parse_or_default(keys::USER_ID)?))
+        .name(owned(map.get(keys::NAME).unwrap_or_default()))
+        .account_id(AccountId::new(map.parse_or_default(keys::ACCOUNT_ID)?))
+        .build();
+
+    let request = FriendRequest::builder()
+        .id(FriendRequestId::new(map.parse_required(keys::ID)?))
+        .user(user)
+        .request_type(request_type)
+        .content(parse_base64(&map, keys::CONTENT)?)
+        .maybe_created_at(parse_instant(&map, keys::CREATED_AT))
+        .read(!map.parse_bool(keys::NEW))
+        .build();
+
+    Ok(request)
+}
+
+pub(crate) fn parse_friend_requests(
+    string: &str,
+    request_type: Type,
+) -> Result<Vec<FriendRequest<'static>>, robtop::Error> {
+    string
+        .split(pages::ITEM_DELIMITER)
+        .filter(|item| !item.is_empty())
+        .map(|item| parse_friend_request(item, request_type))
+        .collect()
+}
+
+fn succeeded(response: &str) -> Result<(), FriendRequestError> {
+    if response.trim() == SUCCESS {
+        Ok(())
+    } else {
+        Err(FriendRequestError::Failed)
+    }
+}
+
+impl Authenticated<'_> {
+    pub async fn get_friend_requests(
+        &self,
+        request_type: Type,
+        page: u32,
+    ) -> Result<Page<FriendRequest<'static>>, GetFriendRequestsError> {
+        let form = GetFriendRequestsForm {
+            auth: self.state.auth.form(),
+            page,
+            total: 0,
+            get_sent: flag(request_type.is_outgoing()),
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::GET_FRIEND_REQUESTS, &form)
+            .await?;
+
+        let response = response.trim();
+
+        if response == NO_RESULTS {
+            return Ok(Page::empty());
+        }
+
+        let (requests, trailer) = response
+            .split_once(pages::SECTION_DELIMITER)
+            .unwrap_or((response, ""));
+
+        let requests = parse_friend_requests(requests, request_type)?;
+
+        let page = pages::parse_page(requests, trailer)?;
+
+        Ok(page)
+    }
+
+    pub async fn send_friend_request(
+        &self,
+        account_id: AccountId,
+        message: Option<&str>,
+    ) -> Result<(), SendFriendRequestError> {
+        let form = SendFriendRequestForm {
+            auth: self.state.auth.form(),
+            to_account_id: account_id,
+            comment: base64::encode(message.unwrap_or_default()),
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::SEND_FRIEND_REQUEST, &form)
+            .await?;
+
+        let response = response.trim();
+
+        match response {
+            SUCCESS => Ok(()),
+            CLOSED => Err(SendFriendRequestError::Closed),
+            _ => Err(SendFriendRequestError::Failed {
+                response: response.to_owned(),
+            }),
+        }
+    }
+
+    pub async fn accept_friend_request(
+        &self,
+        account_id: AccountId,
+        request_id: FriendRequestId,
+    ) -> Result<(), FriendRequestError> {
+        let form = AcceptFriendRequestForm {
+            auth: self.state.auth.form(),
+            target_account_id: account_id,
+            request_id,
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::ACCEPT_FRIEND_REQUEST, &form)
+            .await?;
+
+        succeeded(&response)
+    }
+
+    pub async fn delete_friend_request(
+        &self,
+        account_id: AccountId,
+        request_type: Type,
+    ) -> Result<(), FriendRequestError> {
+        self.delete_friend_requests(&[account_id], request_type)
+            .await
+    }
+
+    pub async fn delete_friend_requests(
+        &self,
+        account_ids: &[AccountId],
+        request_type: Type,
+    ) -> Result<(), FriendRequestError> {
+        let accounts = account_ids
+            .iter()
+            .map(AccountId::to_string)
+            .collect::<Vec<_>>()
+            .join(LIST_DELIMITER);
+
+        let form = DeleteFriendRequestsForm {
+            auth: self.state.auth.form(),
+            accounts,
+            is_sender: flag(request_type.is_outgoing()),
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::DELETE_FRIEND_REQUESTS, &form)
+            .await?;
+
+        succeeded(&response)
+    }
+
+    pub async fn read_friend_request(
+        &self,
+        request_id: FriendRequestId,
+    ) -> Result<(), FriendRequestError> {
+        let form = ReadFriendRequestForm {
+            auth: self.state.auth.form(),
+            request_id,
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::READ_FRIEND_REQUEST, &form)
+            .await?;
+
+        succeeded(&response)
+    }
+}
@@ -0,0 +1,205 @@
+use gd_core::types::id::{AccountId, UserId};
+use gd_entities::users::{User, UserReference};
+use gd_internals::{
+    http::RequestError,
+    robtop::{self, Map},
+};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{
+    auth::AuthForm,
+    client::Authenticated,
+    endpoints, pages, secrets,
+    users::{self, DELIMITER},
+    utils::owned,
+};
+
+pub const FAILED: &str = "-1";
+
+pub const NO_RESULTS: &str = "-2";
+
+pub const SUCCESS: &str = "1";
+
+pub mod lists {
+    pub const FRIENDS: u8 = 0;
+    pub const BLOCKED: u8 = 1;
+}
+
+pub(crate) mod keys {
+    pub const NEW: &str = "41";
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Friend<'f> {
+    pub user: User<'f>,
+    pub new: bool,
+}
+
+impl Friend<'_> {
+    pub const fn is_new(&self) -> bool {
+        self.new
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum UserListError {
+    #[error("failed to get user list")]
+    Failed,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum RelationshipError {
+    #[error("failed to update relationship")]
+    Failed,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UserListForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    #[serde(rename = "type")]
+    list_type: u8,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RelationshipForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    #[serde(rename = "targetAccountID")]
+    target_account_id: AccountId,
+    secret: &'f str,
+}
+
+pub(crate) fn parse_listed_user(map: &Map<'_>) -> Result<User<'static>, robtop::Error> {
+    let reference = UserReference::builder()
+        .id(UserId::new(map.parse_required(users::keys::ID)?))
+        .name(owned(map.get(users::keys::NAME).unwrap_or_default()))
+        .account_id(AccountId::new(map.parse_required(users::keys::ACCOUNT_ID)?))
+        .build();
+
+    let user = User::builder()
+        .reference(reference)
+        .cosmetics(users::parse_cosmetics(map)?)
+        .build();
+
+    Ok(user)
+}
+
+pub(crate) fn parse_friend(string: &str) -> Result<Friend<'static>, robtop::Error> {
+    let map = Map::parse(string, DELIMITER);
+
+    let friend = Friend {
+        user: parse_listed_user(&map)?,
+        new: map.parse_bool(keys::NEW),
+    };
+
+    Ok(friend)
+}
+
+pub(crate) fn parse_blocked(string: &str) -> Result<User<'static>, robtop::Error> {
+    parse_listed_user(&Map::parse(string, DELIMITER))
+}
+
+impl Authenticated<'_> {
+    async fn get_user_list(&self, list_type: u8) -> Result<String, RequestError> {
+        let form = UserListForm {
+            auth: self.state.auth.form(),
+            list_type,
+            secret: secrets::COMMON,
+        };
+
+        self.session
+            .http
+            .post(endpoints::GET_USER_LIST, &form)
+            .await
+    }
+
+    pub async fn friends(&self) -> Result<Vec<Friend<'static>>, UserListError> {
+        let response = self.get_user_list(lists::FRIENDS).await?;
+
+        let response = response.trim();
+
+        if response == FAILED {
+            return Err(UserListError::Failed);
+        }
+
+        if response == NO_RESULTS {
+            return Ok(Vec::new());
+        }
+
+        let friends = response
+            .split(pages::ITEM_DELIMITER)
+            .filter(|item| !item.is_empty())
+            .map(parse_friend)
+            .collect::<Result<_, _>>()?;
+
+        Ok(friends)
+    }
+
+    pub async fn blocked(&self) -> Result<Vec<User<'static>>, UserListError> {
+        let response = self.get_user_list(lists::BLOCKED).await?;
+
+        let response = response.trim();
+
+        if response == FAILED {
+            return Err(UserListError::Failed);
+        }
+
+        if response == NO_RESULTS {
+            return Ok(Vec::new());
+        }
+
+        let blocked = response
+            .split(pages::ITEM_DELIMITER)
+            .filter(|item| !item.is_empty())
+            .map(parse_blocked)
+            .collect::<Result<_, _>>()?;
+
+        Ok(blocked)
+    }
+
+    async fn update_relationship(
+        &self,
+        endpoint: &str,
+        account_id: AccountId,
+    ) -> Result<(), RelationshipError> {
+        let form = RelationshipForm {
+            auth: self.state.auth.form(),
+            target_account_id: account_id,
+            secret: secrets::COMMON,
+        };
+
+        let response = self.session.http.post(endpoint, &form).await?;
+
+        if response.trim() != SUCCESS {
+            return Err(RelationshipError::Failed);
+        }
+
+        Ok(())
+    }
+
+    pub async fn remove_friend(&self, account_id: AccountId) -> Result<(), RelationshipError> {
+        self.update_relationship(endpoints::REMOVE_FRIEND, account_id)
+            .await
+    }
+
+    pub async fn block(&self, account_id: AccountId) -> Result<(), RelationshipError> {
+        self.update_relationship(endpoints::BLOCK_USER, account_id)
+            .await
+    }
+
+    pub async fn unblock(&self, account_id: AccountId) -> Result<(), RelationshipError> {
+        self.update_relationship(endpoints::UNBLOCK_USER, account_id)
+            .await
+    }
+}
@@ -0,0 +1,92 @@
+use gd_core::types::id::GauntletId;
+use gd_entities::gauntlets::Gauntlet;
+use gd_enums::gauntlets::Name;
+use gd_internals::{
+    http::RequestError,
+    robtop::{self, Map},
+};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{
+    client::Client,
+    endpoints, pages, secrets,
+    state::State,
+    utils::{flag, parse_level_ids},
+    versions,
+};
+
+pub const DELIMITER: &str = ":";
+
+pub const NO_RESULTS: &str = "-1";
+
+pub(crate) mod keys {
+    pub const ID: &str = "1";
+    pub const LEVEL_IDS: &str = "3";
+}
+
+#[derive(Debug, Error)]
+pub enum GetGauntletsError {
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GetGauntletsForm<'f> {
+    special: Option<u8>,
+    game_version: u16,
+    binary_version: u16,
+    secret: &'f str,
+}
+
+pub(crate) fn parse_gauntlet(string: &str) -> Result<Gauntlet, robtop::Error> {
+    let map = Map::parse(string, DELIMITER);
+
+    let id: u64 = map.parse_required(keys::ID)?;
+
+    let gauntlet = Gauntlet::builder()
+        .id(GauntletId::new(id))
+        .name(Name::from_id(id))
+        .level_ids(parse_level_ids(&map, keys::LEVEL_IDS)?)
+        .build();
+
+    Ok(gauntlet)
+}
+
+impl<S: State> Client<S> {
+    pub async fn get_gauntlets(&self, special: bool) -> Result<Vec<Gauntlet>, GetGauntletsError> {
+        let form = GetGauntletsForm {
+            special: flag(special),
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::GET_GAUNTLETS, &form)
+            .await?;
+
+        let response = response.trim();
+
+        if response == NO_RESULTS {
+            return Ok(Vec::new());
+        }
+
+        let (gauntlets, _) = response
+            .split_once(pages::SECTION_DELIMITER)
+            .unwrap_or((response, ""));
+
+        let gauntlets = gauntlets
+            .split(pages::ITEM_DELIMITER)
+            .filter(|item| !item.is_empty())
+            .map(parse_gauntlet)
+            .collect::<Result<_, _>>()?;
+
+        Ok(gauntlets)
+    }
+}
@@ -0,0 +1,430 @@
+use bon::Builder;
+use gd_core::types::{
+    id::{LevelId, TimelyId},
+    record::{Percent, Record},
+    reward::RewardCoins,
+    statistics::{Attempts, Clicks, Place, Points},
+    time::Duration,
+};
+use gd_crypto::{check, keys as crypto_keys, random, salts};
+use gd_entities::users::{User, UserLeaderboard};
+use gd_enums::leaderboards::{LeaderboardKind, LevelLeaderboardKind, PlatformerMode};
+use gd_internals::{
+    http::RequestError,
+    robtop::{self, InvalidError, Map},
+};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{
+    auth::AuthForm,
+    client::{Authenticated, Client},
+    endpoints, pages, secrets,
+    state::State,
+    users::{self, DELIMITER},
+    utils::{milliseconds, parse_instant},
+    versions,
+};
+
+pub const NO_RESULTS: &str = "-1";
+
+pub mod queries {
+    pub const TOP: &str = "top";
+    pub const CREATORS: &str = "creators";
+    pub const FRIENDS: &str = "friends";
+    pub const RELATIVE: &str = "relative";
+}
+
+pub const SEED_LENGTH: usize = 10;
+
+pub const COMPLETED: u8 = 100;
+
+pub const VERIFIED: u8 = 1;
+
+pub(crate) mod keys {
+    pub const RECORD: &str = "3";
+    pub const PLACE: &str = "6";
+    pub const COINS: &str = "13";
+    pub const RECORDED_AT: &str = "42";
+}
+
+pub mod offsets {
+    pub const ATTEMPTS: u32 = 8354;
+    pub const PERCENT: u32 = 8354;
+    pub const CLICKS: u32 = 3991;
+    pub const TIME: u64 = 4085;
+    pub const COINS: u32 = 5819;
+}
+
+pub mod seeds {
+    pub const PLAYED: u64 = 1482;
+    pub const BASE: u64 = 50_028_039;
+}
+
+pub const PROGRESS_DELIMITER: &str = ",";
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Builder)]
+pub struct ScoreSubmission {
+    pub level_id: LevelId,
+    pub record: Record,
+    #[builder(default)]
+    pub coins: RewardCoins,
+    #[builder(default)]
+    pub attempts: Attempts,
+    #[builder(default)]
+    pub clicks: Clicks,
+    #[builder(default)]
+    pub time: Duration,
+    #[builder(default)]
+    pub timely_id: TimelyId,
+    #[builder(default)]
+    pub progresses: Vec<Percent>,
+    #[builder(default)]
+    pub played: bool,
+}
+
+impl ScoreSubmission {
+    pub const fn percent(&self) -> u8 {
+        match self.record {
+            Record::Percent(percent) => percent.get(),
+            _ => COMPLETED,
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        let played = u64::from(self.played);
+        let clicks = u64::from(self.clicks.get() + offsets::CLICKS);
+        let percent = u64::from(u32::from(self.percent()) + offsets::PERCENT);
+        let time = self.time.get().as_secs() + offsets::TIME;
+
+        (seeds::PLAYED * (played + 1) + clicks * percent + time * time).saturating_sub(seeds::BASE)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum LeaderboardError {
+    #[error("leaderboard requires authentication")]
+    Unauthenticated,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum LevelLeaderboardError {
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Serialize)]
+struct ScoreForm {
+    s1: u32,
+    s2: u32,
+    s3: u64,
+    s4: u64,
+    s5: u32,
+    s6: String,
+    s7: String,
+    s8: u32,
+    s9: u32,
+    s10: TimelyId,
+    s11: u8,
+    chk: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LevelLeaderboardForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    #[serde(rename = "levelID")]
+    level_id: LevelId,
+    #[serde(rename = "type")]
+    kind: u8,
+    percent: Option<u8>,
+    mode: Option<u8>,
+    time: Option<u64>,
+    points: Option<Points>,
+    #[serde(flatten)]
+    score: Option<ScoreForm>,
+    game_version: u16,
+    binary_version: u16,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LeaderboardForm<'f> {
+    #[serde(rename = "type")]
+    kind: &'f str,
+    count: u32,
+    game_version: u16,
+    binary_version: u16,
+    #[serde(flatten)]
+    auth: Option<AuthForm>,
+    secret: &'f str,
+}
+
+pub const fn query(kind: LeaderboardKind) -> &'static str {
+    match kind {
+        LeaderboardKind::Top => queries::TOP,
+        LeaderboardKind::Creators => queries::CREATORS,
+        LeaderboardKind::Friends => queries::FRIENDS,
+        LeaderboardKind::Relative => queries::RELATIVE,
+    }
+}
+
+pub(crate) fn parse_ranked_user(string: &str) -> Result<User<'static>, robtop::Error> {
+    let mut user = users::parse_partial_user(string)?;
+
+    let map = Map::parse(string, DELIMITER);
+
+    user.place = Some(Place::new(map.parse_required(keys::PLACE)?));
+
+    Ok(user)
+}
+
+pub const fn platformer_mode(record: Record) -> Option<PlatformerMode> {
+    match record {
+        Record::Percent(_) => None,
+        Record::Duration(_) => Some(PlatformerMode::Time),
+        Record::Points(_) => Some(PlatformerMode::Points),
+    }
+}
+
+pub(crate) fn parse_record(
+    map: &Map<'_>,
+    mode: Option<PlatformerMode>,
+) -> Result<Record, robtop::Error> {
+    let value: u64 = map.parse_or_default(keys::RECORD)?;
+
+    let invalid = || InvalidError::new(keys::RECORD, value.to_string());
+
+    let record = match mode {
+        None => Record::Percent(
+            u8::try_from(value)
+                .ok()
+                .and_then(Percent::new)
+                .ok_or_else(invalid)?,
+        ),
+        Some(PlatformerMode::Time) => Record::Duration(milliseconds(value)),
+        Some(PlatformerMode::Points) => {
+            Record::Points(Points::new(u32::try_from(value).map_err(|_| invalid())?))
+        }
+    };
+
+    Ok(record)
+}
+
+pub(crate) fn parse_level_ranked_user(
+    string: &str,
+    mode: Option<PlatformerMode>,
+) -> Result<User<'static>, robtop::Error> {
+    let map = Map::parse(string, DELIMITER);
+
+    let leaderboard = UserLeaderboard::builder()
+        .record(parse_record(&map, mode)?)
+        .coins(RewardCoins::new(map.parse_or_default(keys::COINS)?))
+        .maybe_recorded_at(parse_instant(&map, keys::RECORDED_AT))
+        .build();
+
+    let user = User::builder()
+        .reference(users::parse_reference(&map)?)
+        .cosmetics(users::parse_cosmetics(&map)?)
+        .place(Place::new(map.parse_or_default(keys::PLACE)?))
+        .leaderboard(leaderboard)
+        .build();
+
+    Ok(user)
+}
+
+pub(crate) fn parse_level_leaderboard(
+    response: &str,
+    mode: Option<PlatformerMode>,
+) -> Result<Vec<User<'static>>, robtop::Error> {
+    let response = response.trim();
+
+    if response == NO_RESULTS {
+        return Ok(Vec::new());
+    }
+
+    response
+        .split(pages::ITEM_DELIMITER)
+        .filter(|item| !item.is_empty())
+        .map(|item| parse_level_ranked_user(item, mode))
+        .collect()
+}
+
+impl<S: State> Client<S> {
+    pub async fn get_leaderboard(
+        &self,
+        kind: LeaderboardKind,
+        count: u32,
+    ) -> Result<Vec<User<'static>>, LeaderboardError> {
+        let auth = self.state.auth().map(|auth| auth.form());
+
+        if kind.is_personal() && auth.is_none() {
+            return Err(LeaderboardError::Unauthenticated);
+        }
+
+        let form = LeaderboardForm {
+            kind: query(kind),
+            count,
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            auth,
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::GET_LEADERBOARD, &form)
+            .await?;
+
+        let response = response.trim();
+
+        if response == NO_RESULTS {
+            return Ok(Vec::new());
+        }
+
+        let users = response
+            .split(pages::ITEM_DELIMITER)
+            .filter(|item| !item.is_empty())
+            .map(parse_ranked_user)
+            .collect::<Result<_, _>>()?;
+
+        Ok(users)
+    }
+}
+
+impl Authenticated<'_> {
+    fn score_form(&self, submission: &ScoreSubmission) -> ScoreForm {
+        let account_id = self.state.auth.reference.account_id;
+
+        let percent = submission.percent();
+
+        let level_id = submission.level_id;
+        let seconds = submission.time.get().as_secs();
+        let clicks = submission.clicks.get();
+        let attempts = submission.attempts.get();
+        let coins = submission.coins.get();
+        let timely_id = submission.timely_id;
+        let rs = random::string(SEED_LENGTH);
+        let difference = COMPLETED - percent;
+
+        let chk = check::of_with_salt(
+            format!(
+                "{account_id}{level_id}{percent}{seconds}{clicks}{attempts}\
+                {percent}{difference}{VERIFIED}{coins}{timely_id}{rs}"
+            ),
+            salts::LEVEL_LEADERBOARD,
+            crypto_keys::LEVEL_LEADERBOARD,
+        );
+
+        ScoreForm {
+            s1: attempts + offsets::ATTEMPTS,
+            s2: clicks + offsets::CLICKS,
+            s3: seconds + offsets::TIME,
+            s4: submission.seed(),
+            s5: random::seed(),
+            s6: submission
+                .progresses
+                .iter()
+                .map(|percent| percent.get().to_string())
+                .collect::<Vec<_>>()
+                .join(PROGRESS_DELIMITER),
+            s7: rs,
+            s8: attempts,
+            s9: u32::from(coins) + offsets::COINS,
+            s10: timely_id,
+            s11: u8::from(submission.played),
+            chk,
+        }
+    }
+
+    async fn level_leaderboard(
+        &self,
+        level_id: LevelId,
+        kind: LevelLeaderboardKind,
+        mode: Option<PlatformerMode>,
+        record: Option<Record>,
+        score: Option<ScoreForm>,
+    ) -> Result<Vec<User<'static>>, LevelLeaderboardError> {
+        let (percent, time, points) = match record {
+            Some(Record::Percent(percent)) => (Some(percent.get()), None, None),
+            Some(Record::Duration(duration)) => {
+                let millis = duration.get().as_millis();
+
+                (None, Some(u64::try_from(millis).unwrap_or(u64::MAX)), None)
+            }
+            Some(Record::Points(points)) => (None, None, Some(points)),
+            None => (None, None, None),
+        };
+
+        let form = LevelLeaderboardForm {
+            auth: self.state.auth.form(),
+            level_id,
+            kind: kind.value(),
+            percent,
+            mode: mode.map(PlatformerMode::value),
+            time,
+            points,
+            score,
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            secret: secrets::COMMON,
+        };
+
+        let endpoint = if mode.is_some() {
+            endpoints::GET_PLATFORMER_LEADERBOARD
+        } else {
+            endpoints::GET_LEVEL_LEADERBOARD
+        };
+
+        let response = self.session.http.post(endpoint, &form).await?;
+
+        let users = parse_level_leaderboard(&response, mode)?;
+
+        Ok(users)
+    }
+
+    pub async fn get_level_leaderboard(
+        &self,
+        level_id: LevelId,
+        kind: LevelLeaderboardKind,
+    ) -> Result<Vec<User<'static>>, LevelLeaderboardError> {
+        self.level_leaderboard(level_id, kind, None, None, None)
+            .await
+    }
+
+    pub async fn get_platformer_leaderboard(
+        &self,
+        level_id: LevelId,
+        kind: LevelLeaderboardKind,
+        mode: PlatformerMode,
+    ) -> Result<Vec<User<'static>>, LevelLeaderboardError> {
+        self.level_leaderboard(level_id, kind, Some(mode), None, None)
+            .await
+    }
+
+    pub async fn submit_score(
+        &self,
+        submission: &ScoreSubmission,
+        kind: LevelLeaderboardKind,
+    ) -> Result<Vec<User<'static>>, LevelLeaderboardError> {
+        let score = self.score_form(submission);
+
+        self.level_leaderboard(
+            submission.level_id,
+            kind,
+            platformer_mode(submission.record),
+            Some(submission.record),
+            Some(score),
+        )
+        .await
+    }
+}
@@ -0,0 +1,721 @@
+use std::collections::HashMap;
+
+use bon::Builder;
+use gd_core::types::{
+    id::{AccountId, LevelId, SongId, TimelyId, UserId},
+    password::{Code, Password},
+    reward::{Reward, RewardCoins, RewardMoons, RewardStars},
+    statistics::{Downloads, ObjectCount, Rating, Score},
+    str::Str,
+    time::TimeSteps,
+    version::Version,
+};
+use gd_crypto::{keys as crypto_keys, robtop as crypto_robtop};
+use gd_entities::{
+    levels::{Capacity, Data, Level, LevelReference},
+    songs::{Song, SongReference},
+    users::UserReference,
+};
+use gd_enums::{
+    levels::{Difficulty, Length, RateType},
+    searches, timely,
+};
+use gd_internals::{
+    http::RequestError,
+    robtop::{self, InvalidError, Map},
+};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{
+    auth::AuthForm,
+    client::Client,
+    endpoints,
+    pages::{self, Page},
+    secrets, songs,
+    state::State,
+    utils::{flag, owned, parse_base64, parse_instant, parse_value, seconds},
+    versions,
+};
+
+pub const DELIMITER: &str = ":";
+
+pub const CREATOR_DELIMITER: &str = ":";
+
+pub const LIST_DELIMITER: &str = ",";
+
+pub const NO_RESULTS: &str = "-1";
+
+pub const ANY: &str = "-";
+
+pub const NOT_FOUND: &str = "-1";
+
+pub const CAPACITY_DELIMITER: &str = "_";
+
+pub const NO_COPY: &str = "0";
+pub const FREE_COPY: &str = "1";
+pub const COPY_PREFIX: char = '1';
+
+pub(crate) mod keys {
+    pub const ID: &str = "1";
+    pub const NAME: &str = "2";
+    pub const DESCRIPTION: &str = "3";
+    pub const DATA: &str = "4";
+    pub const VERSION: &str = "5";
+    pub const CREATOR_ID: &str = "6";
+    pub const DIFFICULTY_DENOMINATOR: &str = "8";
+    pub const DIFFICULTY_NUMERATOR: &str = "9";
+    pub const DOWNLOADS: &str = "10";
+    pub const OFFICIAL_SONG_ID: &str = "12";
+    pub const GAME_VERSION: &str = "13";
+    pub const RATING: &str = "14";
+    pub const LENGTH: &str = "15";
+    pub const DEMON: &str = "17";
+    pub const STARS: &str = "18";
+    pub const SCORE: &str = "19";
+    pub const AUTO: &str = "25";
+    pub const PASSWORD: &str = "27";
+    pub const CREATED_AT: &str = "28";
+    pub const UPDATED_AT: &str = "29";
+    pub const ORIGINAL_ID: &str = "30";
+    pub const TWO_PLAYER: &str = "31";
+    pub const CUSTOM_SONG_ID: &str = "35";
+    pub const CAPACITY: &str = "36";
+    pub const COINS: &str = "37";
+    pub const VERIFIED_COINS: &str = "38";
+    pub const REQUESTED_STARS: &str = "39";
+    pub const LOW_DETAIL: &str = "40";
+    pub const TIMELY_ID: &str = "41";
+    pub const EPIC: &str = "42";
+    pub const DEMON_DIFFICULTY: &str = "43";
+    pub const OBJECT_COUNT: &str = "45";
+    pub const EDITOR_TIME: &str = "46";
+    pub const COPIES_TIME: &str = "47";
+    pub const TIME_STEPS: &str = "57";
+}
+
+pub mod special {
+    pub const DAILY: i64 = -1;
+    pub const WEEKLY: i64 = -2;
+    pub const EVENT: i64 = -3;
+}
+
+pub mod filters {
+    pub const NOT_AVAILABLE: i8 = -1;
+    pub const DEMON: i8 = -2;
+    pub const AUTO: i8 = -3;
+
+    pub const EASY: i8 = 1;
+    pub const NORMAL: i8 = 2;
+    pub const HARD: i8 = 3;
+    pub const HARDER: i8 = 4;
+    pub const INSANE: i8 = 5;
+
+    pub const EASY_DEMON: u8 = 1;
+    pub const MEDIUM_DEMON: u8 = 2;
+    pub const HARD_DEMON: u8 = 3;
+    pub const INSANE_DEMON: u8 = 4;
+    pub const EXTREME_DEMON: u8 = 5;
+}
+
+pub mod demons {
+    pub const EASY: u8 = 3;
+    pub const MEDIUM: u8 = 4;
+    pub const INSANE: u8 = 5;
+    pub const EXTREME: u8 = 6;
+}
+
+pub const DIFFICULTY_SCALE: u8 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SongFilter {
+    Official(SongId),
+    Custom(SongId),
+}
+
+impl SongFilter {
+    pub const fn id(self) -> SongId {
+        match self {
+            Self::Official(id) | Self::Custom(id) => id,
+        }
+    }
+
+    pub const fn is_custom(self) -> bool {
+        matches!(self, Self::Custom(_))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Completion {
+    Completed(Vec<LevelId>),
+    Uncompleted(Vec<LevelId>),
+}
+
+impl Completion {
+    pub fn ids(&self) -> &[LevelId] {
+        match self {
+            Self::Completed(ids) | Self::Uncompleted(ids) => ids,
+        }
+    }
+
+    pub const fn is_completed(&self) -> bool {
+        matches!(self, Self::Completed(_))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Builder)]
+pub struct LevelQuery<'q> {
+    #[builder(default)]
+    pub search_type: searches::Type,
+    #[builder(into)]
+    pub query: Option<Str<'q>>,
+    #[builder(default)]
+    pub difficulties: Vec<Difficulty>,
+    #[builder(default)]
+    pub lengths: Vec<Length>,
+    pub rated: Option<bool>,
+    #[builder(default)]
+    pub featured: bool,
+    #[builder(default)]
+    pub epic: bool,
+    #[builder(default)]
+    pub legendary: bool,
+    #[builder(default)]
+    pub mythic: bool,
+    #[builder(default)]
+    pub original: bool,
+    #[builder(default)]
+    pub two_player: bool,
+    #[builder(default)]
+    pub coins: bool,
+    pub song: Option<SongFilter>,
+    pub completion: Option<Completion>,
+    #[builder(default)]
+    pub followed: Vec<AccountId>,
+    #[builder(default)]
+    pub page: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DownloadId {
+    Level(LevelId),
+    Timely(timely::Type),
+}
+
+impl DownloadId {
+    pub const fn value(self) -> Option<i64> {
+        let value = match self {
+            Self::Level(id) => id.get() as i64,
+            Self::Timely(timely::Type::Daily) => special::DAILY,
+            Self::Timely(timely::Type::Weekly) => special::WEEKLY,
+            Self::Timely(timely::Type::Event) => special::EVENT,
+            Self::Timely(timely::Type::NotTimely) => return None,
+        };
+
+        Some(value)
+    }
+}
+
+impl From<LevelId> for DownloadId {
+    fn from(id: LevelId) -> Self {
+        Self::Level(id)
+    }
+}
+
+impl From<timely::Type> for DownloadId {
+    fn from(timely_type: timely::Type) -> Self {
+        Self::Timely(timely_type)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FoundLevel<'l> {
+    pub level: Level<'l>,
+    pub song: Option<Song<'l>>,
+}
+
+#[derive(Debug, Error)]
+pub enum SearchLevelsError {
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum DownloadLevelError {
+    #[error("level not found")]
+    NotFound,
+    #[error("level is not timely")]
+    NotTimely,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DownloadLevelForm<'f> {
+    #[serde(rename = "levelID")]
+    level_id: i64,
+    game_version: u16,
+    binary_version: u16,
+    #[serde(flatten)]
+    auth: Option<AuthForm>,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SearchLevelsForm<'f> {
+    #[serde(rename = "type")]
+    search_type: u8,
+    #[serde(rename = "str")]
+    query: &'f str,
+    diff: String,
+    demon_filter: Option<u8>,
+    len: String,
+    star: Option<u8>,
+    no_star: Option<u8>,
+    featured: Option<u8>,
+    epic: Option<u8>,
+    legendary: Option<u8>,
+    mythic: Option<u8>,
+    original: Option<u8>,
+    two_player: Option<u8>,
+    coins: Option<u8>,
+    song: Option<SongId>,
+    custom_song: Option<u8>,
+    uncompleted: Option<u8>,
+    only_completed: Option<u8>,
+    completed_levels: Option<String>,
+    followed: Option<String>,
+    page: u32,
+    total: u32,
+    game_version: u16,
+    binary_version: u16,
+    #[serde(flatten)]
+    auth: Option<AuthForm>,
+    secret: &'f str,
+}
+
+pub(crate) fn join<T, I: IntoIterator<Item = T>, F: FnMut(T) -> String>(
+    items: I,
+    function: F,
+) -> String {
+    items
+        .into_iter()
+        .map(function)
+        .collect::<Vec<_>>()
+        .join(LIST_DELIMITER)
+}
+
+pub(crate) fn difficulty_filter(difficulty: Difficulty) -> i8 {
+    match difficulty {
+        Difficulty::NotAvailable => filters::NOT_AVAILABLE,
+        Difficulty::Auto => filters::AUTO,
+        Difficulty::Easy => filters::EASY,
+        Difficulty::Normal => filters::NORMAL,
+        Difficulty::Hard => filters::HARD,
+        Difficulty::Harder => filters::HARDER,
+        Difficulty::Insane => filters::INSANE,
+        _ => filters::DEMON,
+    }
+}
+
+pub(crate) fn demon_filter(difficulty: Difficulty) -> Option<u8> {
+    match difficulty {
+        Difficulty::EasyDemon => Some(filters::EASY_DEMON),
+        Difficulty::MediumDemon => Some(filters::MEDIUM_DEMON),
+        Difficulty::HardDemon => Some(filters::HARD_DEMON),
+        Difficulty::InsaneDemon => Some(filters::INSANE_DEMON),
+        Difficulty::ExtremeDemon => Some(filters::EXTREME_DEMON),
+        _ => None,
+    }
+}
+
+pub(crate) fn or_any(string: String) -> String {
+    if string.is_empty() {
+        ANY.to_owned()
+    } else {
+        string
+    }
+}
+
+impl LevelQuery<'_> {
+    fn form<'f>(&'f self, auth: Option<AuthForm>) -> SearchLevelsForm<'f> {
+        let mut difficulties: Vec<i8> = self
+            .difficulties
+            .iter()
+            .copied()
+            .map(difficulty_filter)
+            .collect();
+
+        difficulties.sort_unstable();
+        difficulties.dedup();
+
+        let demon_filter = self.difficulties.iter().copied().find_map(demon_filter);
+
+        let completion = self.completion.as_ref();
+
+        let completed_levels = completion.map(|completion| {
+            format!("({ids})", ids = join(completion.ids(), |id| id.to_string()))
+        });
+
+        SearchLevelsForm {
+            search_type: self.search_type.value(),
+            query: self.query.as_deref().unwrap_or_default(),
+            diff: or_any(join(difficulties, |difficulty| difficulty.to_string())),
+            demon_filter,
+            len: or_any(join(&self.lengths, |length| length.value().to_string())),
+            star: self.rated.and_then(flag),
+            no_star: self.rated.and_then(|rated| flag(!rated)),
+            featured: flag(self.featured),
+            epic: flag(self.epic),
+            legendary: flag(self.legendary),
+            mythic: flag(self.mythic),
+            original: flag(self.original),
+            two_player: flag(self.two_player),
+            coins: flag(self.coins),
+            song: self.song.map(SongFilter::id),
+            custom_song: self.song.and_then(|song| flag(song.is_custom())),
+            uncompleted: completion.and_then(|completion| flag(!completion.is_completed())),
+            only_completed: completion.and_then(|completion| flag(completion.is_completed())),
+            completed_levels,
+            followed: (!self.followed.is_empty())
+                .then(|| join(&self.followed, |account_id| account_id.to_string())),
+            page: self.page,
+            total: 0,
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            auth,
+            secret: secrets::COMMON,
+        }
+    }
+}
+
+pub(crate) fn parse_difficulty(map: &Map<'_>) -> Result<Difficulty, robtop::Error> {
+    if map.parse_bool(keys::AUTO) {
+        return Ok(Difficulty::Auto);
+    }
+
+    if map.parse_bool(keys::DEMON) {
+        let difficulty = match map.parse_or_default(keys::DEMON_DIFFICULTY)? {
+            demons::EASY => Difficulty::EasyDemon,
+            demons::MEDIUM => Difficulty::MediumDemon,
+            demons::INSANE => Difficulty::InsaneDemon,
+            demons::EXTREME => Difficulty::ExtremeDemon,
+            _ => Difficulty::HardDemon,
+        };
+
+        return Ok(difficulty);
+    }
+
+    let denominator: u8 = map.parse_or_default(keys::DIFFICULTY_DENOMINATOR)?;
+
+    if denominator == 0 {
+        return Ok(Difficulty::NotAvailable);
+    }
+
+    let numerator: u8 = map.parse_or_default(keys::DIFFICULTY_NUMERATOR)?;
+
+    let difficulty = match numerator / DIFFICULTY_SCALE {
+        1 => Difficulty::Easy,
+        2 => Difficulty::Normal,
+        3 => Difficulty::Hard,
+        4 => Difficulty::Harder,
+        5 => Difficulty::Insane,
+        _ => Difficulty::NotAvailable,
+    };
+
+    Ok(difficulty)
+}
+
+pub(crate) fn parse_rate_type(map: &Map<'_>) -> Result<RateType, robtop::Error> {
+    let epic: u8 = map.parse_or_default(keys::EPIC)?;
+
+    let rate_type = match epic {
+        1 => RateType::Epic,
+        2 => RateType::Legendary,
+        3 => RateType::Mythic,
+        _ if map.parse_or_default::<u32>(keys::SCORE)? > 0 => RateType::Featured,
+        _ if map.parse_or_default::<u8>(keys::STARS)? > 0 => RateType::Rated,
+        _ => RateType::NotRated,
+    };
+
+    Ok(rate_type)
+}
+
+pub(crate) fn reward(length: Length, value: u8) -> Reward {
+    if length.is_platformer() {
+        Reward::Moons(RewardMoons::new(value))
+    } else {
+        Reward::Stars(RewardStars::new(value))
+    }
+}
+
+pub(crate) fn parse_song_reference(map: &Map<'_>) -> Result<SongReference, robtop::Error> {
+    let custom_id: u64 = map.parse_or_default(keys::CUSTOM_SONG_ID)?;
+
+    let reference = if custom_id == 0 {
+        SongReference::builder()
+            .id(SongId::new(map.parse_or_default(keys::OFFICIAL_SONG_ID)?))
+            .build()
+    } else {
+        SongReference::builder()
+            .id(SongId::new(custom_id))
+            .custom(true)
+            .build()
+    };
+
+    Ok(reference)
+}
+
+pub(crate) fn parse_creators(
+    string: &str,
+) -> Result<HashMap<UserId, UserReference<'static>>, robtop::Error> {
+    string
+        .split(pages::ITEM_DELIMITER)
+        .filter(|item| !item.is_empty())
+        .map(|item| {
+            let mut parts = item.split(CREATOR_DELIMITER);
+
+            let mut next = || parts.next().unwrap_or_default();
+
+            let id = next();
+            let name = next();
+            let account_id = next();
+
+            let parse = |value: &str| {
+                value
+                    .parse::<u64>()
+                    .map_err(|_| robtop::InvalidError::new(keys::CREATOR_ID, value))
+            };
+
+            let reference = UserReference::builder()
+                .id(UserId::new(parse(id)?))
+                .name(owned(name))
+                .account_id(AccountId::new(parse(account_id)?))
+                .build();
+
+            Ok((reference.id, reference))
+        })
+        .collect()
+}
+
+pub(crate) fn parse_password(map: &Map<'_>) -> Result<Password, robtop::Error> {
+    let Some(value) = map.get(keys::PASSWORD) else {
+        return Ok(Password::NoCopy);
+    };
+
+    let invalid = || InvalidError::new(keys::PASSWORD, value);
+
+    let decoded =
+        crypto_robtop::decode_string(value, crypto_keys::LEVEL_PASSWORD).map_err(|_| invalid())?;
+
+    let password = match decoded.as_str() {
+        NO_COPY => Password::NoCopy,
+        FREE_COPY => Password::FreeCopy,
+        string => {
+            let code = string
+                .strip_prefix(COPY_PREFIX)
+                .and_then(|code| code.parse().ok())
+                .and_then(Code::new)
+                .ok_or_else(invalid)?;
+
+            Password::CodeCopy(code)
+        }
+    };
+
+    Ok(password)
+}
+
+pub(crate) fn parse_capacity(map: &Map<'_>) -> Result<Option<Capacity>, robtop::Error> {
+    if map.get(keys::CAPACITY).is_none() {
+        return Ok(None);
+    }
+
+    map.parse_list(keys::CAPACITY, CAPACITY_DELIMITER).map(Some)
+}
+
+pub(crate) fn parse_timely(map: &Map<'_>) -> Result<(timely::Type, TimelyId), robtop::Error> {
+    let value = map.parse_or_default(keys::TIMELY_ID)?;
+
+    crate::timely::parse_id(keys::TIMELY_ID, value)
+}
+
+pub(crate) fn parse_level_map(
+    map: &Map<'_>,
+    creator: UserReference<'static>,
+) -> Result<Level<'static>, robtop::Error> {
+    let length: Length = parse_value(map, keys::LENGTH)?;
+
+    let reference = LevelReference::builder()
+        .id(LevelId::new(map.parse_required(keys::ID)?))
+        .name(owned(map.get(keys::NAME).unwrap_or_default()))
+        .build();
+
+    let (timely_type, timely_id) = parse_timely(map)?;
+
+    let level = Level::builder()
+        .reference(reference)
+        .description(parse_base64(map, keys::DESCRIPTION)?)
+        .creator(creator)
+        .song(parse_song_reference(map)?)
+        .maybe_data(
+            map.get(keys::DATA)
+                .map(|data| Data::Unprocessed(owned(data))),
+        )
+        .version(Version::new(map.parse_or_default(keys::VERSION)?))
+        .downloads(Downloads::new(map.parse_or_default(keys::DOWNLOADS)?))
+        .game_version(Version::new(map.parse_or_default(keys::GAME_VERSION)?))
+        .rating(Rating::new(map.parse_or_default(keys::RATING)?))
+        .length(length)
+        .difficulty(parse_difficulty(map)?)
+        .reward(reward(length, map.parse_or_default(keys::STARS)?))
+        .requested_reward(reward(length, map.parse_or_default(keys::REQUESTED_STARS)?))
+        .score(Score::new(map.parse_or_default(keys::SCORE)?))
+        .rate_type(parse_rate_type(map)?)
+        .password(parse_password(map)?)
+        .original_id(LevelId::new(map.parse_or_default(keys::ORIGINAL_ID)?))
+        .two_player(map.parse_bool(keys::TWO_PLAYER))
+        .maybe_capacity(parse_capacity(map)?)
+        .coins(RewardCoins::new(map.parse_or_default(keys::COINS)?))
+        .verified_coins(map.parse_bool(keys::VERIFIED_COINS))
+        .low_detail(map.parse_bool(keys::LOW_DETAIL))
+        .object_count(ObjectCount::new(map.parse_or_default(keys::OBJECT_COUNT)?))
+        .maybe_created_at(parse_instant(map, keys::CREATED_AT))
+        .maybe_updated_at(parse_instant(map, keys::UPDATED_AT))
+        .editor_time(seconds(map.parse_or_default(keys::EDITOR_TIME)?))
+        .copies_time(seconds(map.parse_or_default(keys::COPIES_TIME)?))
+        .timely_type(timely_type)
+        .timely_id(timely_id)
+        .time_steps(TimeSteps::new(map.parse_or_default(keys::TIME_STEPS)?))
+        .build();
+
+    Ok(level)
+}
+
+pub(crate) fn resolve_creator(
+    map: &Map<'_>,
+    creators: &HashMap<UserId, UserReference<'static>>,
+) -> Result<UserReference<'static>, robtop::Error> {
+    let id = UserId::new(map.parse_or_default(keys::CREATOR_ID)?);
+
+    let creator = creators.get(&id).cloned().unwrap_or_else(|| {
+        UserReference::builder()
+            .id(id)
+            .name("")
+            .account_id(AccountId::default())
+            .build()
+    });
+
+    Ok(creator)
+}
+
+pub(crate) fn parse_found_levels(
+    levels: &str,
+    creators: &str,
+    songs: &str,
+) -> Result<Vec<FoundLevel<'static>>, robtop::Error> {
+    let creators = parse_creators(creators)?;
+
+    let songs: HashMap<SongId, Song<'static>> = songs::parse_songs(songs)?
+        .into_iter()
+        .map(|song| (song.id, song))
+        .collect();
+
+    levels
+        .split(pages::ITEM_DELIMITER)
+        .filter(|item| !item.is_empty())
+        .map(|item| {
+            let map = Map::parse(item, DELIMITER);
+
+            let level = parse_level_map(&map, resolve_creator(&map, &creators)?)?;
+
+            let song = level
+                .song
+                .is_custom()
+                .then(|| songs.get(&level.song.id).cloned())
+                .flatten();
+
+            Ok(FoundLevel { level, song })
+        })
+        .collect()
+}
+
+impl<S: State> Client<S> {
+    pub async fn search_levels(
+        &self,
+        query: &LevelQuery<'_>,
+    ) -> Result<Page<FoundLevel<'static>>, SearchLevelsError> {
+        let form = query.form(self.state.auth().map(|auth| auth.form()));
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::SEARCH_LEVELS, &form)
+            .await?;
+
+        let response = response.trim();
+
+        if response == NO_RESULTS {
+            return Ok(Page::empty());
+        }
+
+        let mut sections = response.split(pages::SECTION_DELIMITER);
+
+        let mut next = || sections.next().unwrap_or_default();
+
+        let levels = next();
+        let creators = next();
+        let songs = next();
+        let trailer = next();
+
+        let found = parse_found_levels(levels, creators, songs)?;
+
+        let page = pages::parse_page(found, trailer)?;
+
+        Ok(page)
+    }
+
+    pub async fn download_level<I: Into<DownloadId>>(
+        &self,
+        id: I,
+    ) -> Result<Level<'static>, DownloadLevelError> {
+        let form = DownloadLevelForm {
+            level_id: id.into().value().ok_or(DownloadLevelError::NotTimely)?,
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            auth: self.state.auth().map(|auth| auth.form()),
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::DOWNLOAD_LEVEL, &form)
+            .await?;
+
+        let response = response.trim();
+
+        if response == NOT_FOUND {
+            return Err(DownloadLevelError::NotFound);
+        }
+
+        let mut sections = response.split(pages::SECTION_DELIMITER);
+
+        let level = sections.next().unwrap_or_default();
+
+        let creators = sections.nth(2).map(parse_creators).transpose()?;
+
+        let map = Map::parse(level, DELIMITER);
+
+        let creator = resolve_creator(&map, &creators.unwrap_or_default())?;
+
+        let level = parse_level_map(&map, creator)?;
+
+        Ok(level)
+    }
+}
@@ -1,7 +1,36 @@
+pub mod artists;
 pub mod auth;
 pub mod client;
+pub mod comments;
+pub mod endpoints;
+pub mod friend_requests;
+pub mod friends;
+pub mod gauntlets;
+pub mod leaderboards;
+pub mod levels;
+pub mod likes;
+pub mod lists;
+pub mod map_packs;
+pub mod messages;
+pub mod moderation;
+pub mod pages;
+pub mod profiles;
+pub mod quests;
+pub mod rewards;
+pub mod secrets;
+pub mod songs;
 pub mod state;
+pub mod timely;
+pub mod uploads;
+pub mod users;
+pub mod versions;
+
+mod utils;
 
 pub use auth::{Auth, Credentials, Reference};
-pub use client::{Authenticated, Client, Simple};
+pub use client::{Authenticated, Client, LoginError, LoginReason, Simple};
+pub use levels::{DownloadId, FoundLevel, LevelQuery};
+pub use lists::{ListQuery, ListUpload};
+pub use pages::Page;
 pub use state::State;
+pub use timely::Timely;
@@ -0,0 +1,151 @@
+use gd_core::types::id::{CommentId, LevelId, ListId, UserId};
+use gd_crypto::{check, keys as crypto_keys, random, salts};
+use gd_enums::likes::Type;
+use gd_internals::http::RequestError;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{auth::AuthForm, client::Authenticated, endpoints, secrets, utils::flag, versions};
+
+pub const SUCCESS: &str = "1";
+
+pub const NO_RELATED: u64 = 0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LikeTarget {
+    Level(LevelId),
+    LevelComment { id: CommentId, level_id: LevelId },
+    UserComment(CommentId),
+    List(ListId),
+}
+
+impl LikeTarget {
+    pub const fn like_type(self) -> Type {
+        match self {
+            Self::Level(_) => Type::Level,
+            Self::LevelComment { .. } => Type::LevelComment,
+            Self::UserComment(_) => Type::UserComment,
+            Self::List(_) => Type::List,
+        }
+    }
+
+    pub const fn target_id(self) -> u64 {
+        match self {
+            Self::Level(id) => id.get(),
+            Self::LevelComment { id, .. } | Self::UserComment(id) => id.get(),
+            Self::List(id) => id.get(),
+        }
+    }
+
+    pub const fn related_id(self) -> Option<u64> {
+        match self {
+            Self::LevelComment { level_id, .. } => Some(level_id.get()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Like {
+    pub like_type: Type,
+    pub target_id: u64,
+    pub related_id: Option<u64>,
+    pub liked: bool,
+}
+
+impl Like {
+    pub const fn new(target: LikeTarget, liked: bool) -> Self {
+        Self {
+            like_type: target.like_type(),
+            target_id: target.target_id(),
+            related_id: target.related_id(),
+            liked,
+        }
+    }
+
+    pub const fn is_liked(&self) -> bool {
+        self.liked
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum LikeError {
+    #[error("failed to like")]
+    Failed,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LikeForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    udid: String,
+    uuid: UserId,
+    #[serde(rename = "itemID")]
+    item_id: u64,
+    like: u8,
+    #[serde(rename = "type")]
+    like_type: u8,
+    special: u64,
+    rs: String,
+    chk: String,
+    game_version: u16,
+    binary_version: u16,
+    secret: &'f str,
+}
+
+impl Authenticated<'_> {
+    pub async fn send_like(&self, like: &Like) -> Result<(), LikeError> {
+        let auth = &self.state.auth;
+
+        let account_id = auth.reference.account_id;
+        let user_id = auth.reference.id;
+
+        let udid = random::udid();
+        let rs = random::seed_string();
+
+        let special = like.related_id.unwrap_or(NO_RELATED);
+        let item_id = like.target_id;
+        let liked = flag(like.liked).unwrap_or_default();
+        let like_type = like.like_type.value();
+
+        let chk = check::of_with_salt(
+            format!("{special}{item_id}{liked}{like_type}{rs}{account_id}{udid}{user_id}"),
+            salts::LIKE,
+            crypto_keys::LIKE,
+        );
+
+        let form = LikeForm {
+            auth: auth.form(),
+            udid,
+            uuid: user_id,
+            item_id,
+            like: liked,
+            like_type,
+            special,
+            rs,
+            chk,
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            secret: secrets::COMMON,
+        };
+
+        let response = self.session.http.post(endpoints::LIKE, &form).await?;
+
+        if response.trim() != SUCCESS {
+            return Err(LikeError::Failed);
+        }
+
+        Ok(())
+    }
+
+    pub async fn like(&self, target: LikeTarget) -> Result<(), LikeError> {
+        self.send_like(&Like::new(target, true)).await
+    }
+
+    pub async fn dislike(&self, target: LikeTarget) -> Result<(), LikeError> {
+        self.send_like(&Like::new(target, false)).await
+    }
+}
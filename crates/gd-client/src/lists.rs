@@ -0,0 +1,433 @@
+use std::collections::HashMap;
+
+use bon::Builder;
+use gd_core::types::{
+    id::{AccountId, LevelId, ListId, UserId},
+    statistics::{Diamonds, Downloads, Levels, Rating},
+    str::Str,
+    time::Instant,
+    version::Version,
+};
+use gd_crypto::{base64, check, keys as crypto_keys, random, salts};
+use gd_entities::{lists::LevelList, users::UserReference};
+use gd_enums::{levels::Difficulty, searches};
+use gd_internals::{
+    http::RequestError,
+    robtop::{self, InvalidError, Map},
+};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{
+    auth::AuthForm,
+    client::{Authenticated, Client},
+    endpoints,
+    levels::{demon_filter, difficulty_filter, join, or_any, parse_creators},
+    pages::{self, Page},
+    secrets,
+    state::State,
+    utils::{flag, owned, parse_base64, parse_level_ids},
+    versions,
+};
+
+pub const DELIMITER: &str = ":";
+
+pub const NO_RESULTS: &str = "-1";
+
+pub const FIRST_VERSION: u16 = 1;
+
+pub const SUCCESS: &str = "1";
+
+pub const NOT_AVAILABLE: i8 = -1;
+
+pub(crate) mod keys {
+    pub const ID: &str = "1";
+    pub const NAME: &str = "2";
+    pub const DESCRIPTION: &str = "3";
+    pub const VERSION: &str = "5";
+    pub const DIFFICULTY: &str = "7";
+    pub const DOWNLOADS: &str = "10";
+    pub const RATING: &str = "14";
+    pub const FEATURED: &str = "19";
+    pub const CREATED_AT: &str = "28";
+    pub const UPDATED_AT: &str = "29";
+    pub const CREATOR_ACCOUNT_ID: &str = "49";
+    pub const CREATOR_NAME: &str = "50";
+    pub const LEVEL_IDS: &str = "51";
+    pub const REWARD: &str = "55";
+    pub const REWARD_THRESHOLD: &str = "56";
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Builder)]
+pub struct ListQuery<'q> {
+    #[builder(default)]
+    pub search_type: searches::Type,
+    #[builder(into)]
+    pub query: Option<Str<'q>>,
+    #[builder(default)]
+    pub difficulties: Vec<Difficulty>,
+    pub rated: Option<bool>,
+    #[builder(default)]
+    pub followed: Vec<AccountId>,
+    #[builder(default)]
+    pub page: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Builder)]
+pub struct ListUpload<'u> {
+    #[builder(default)]
+    pub id: ListId,
+    #[builder(into)]
+    pub name: Str<'u>,
+    #[builder(default, into)]
+    pub description: Str<'u>,
+    pub level_ids: Vec<LevelId>,
+    #[builder(default)]
+    pub difficulty: Difficulty,
+    #[builder(default = Version::new(FIRST_VERSION))]
+    pub version: Version,
+    #[builder(default)]
+    pub unlisted: bool,
+    #[builder(default)]
+    pub original_id: ListId,
+}
+
+impl ListUpload<'_> {
+    pub fn update(mut self, list: &LevelList<'_>) -> Self {
+        self.id = list.id;
+        self.version = Version::new(list.version.get().saturating_add(1));
+
+        self
+    }
+
+    pub const fn is_update(&self) -> bool {
+        self.id.get() != 0
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SearchListsError {
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum GetListError {
+    #[error("list not found")]
+    NotFound,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+impl From<SearchListsError> for GetListError {
+    fn from(error: SearchListsError) -> Self {
+        match error {
+            SearchListsError::Request(error) => Self::Request(error),
+            SearchListsError::Parse(error) => Self::Parse(error),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum UploadListError {
+    #[error("failed to upload list")]
+    Failed,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+}
+
+#[derive(Debug, Error)]
+pub enum DeleteListError {
+    #[error("failed to delete list")]
+    Failed,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SearchListsForm<'f> {
+    #[serde(rename = "type")]
+    search_type: u8,
+    #[serde(rename = "str")]
+    query: &'f str,
+    diff: String,
+    demon_filter: Option<u8>,
+    star: Option<u8>,
+    no_star: Option<u8>,
+    followed: Option<String>,
+    page: u32,
+    game_version: u16,
+    binary_version: u16,
+    #[serde(flatten)]
+    auth: Option<AuthForm>,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UploadListForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    #[serde(rename = "listID")]
+    list_id: ListId,
+    list_name: &'f str,
+    list_desc: String,
+    list_levels: &'f str,
+    difficulty: i8,
+    original: ListId,
+    unlisted: Option<u8>,
+    list_version: Version,
+    seed: String,
+    seed2: String,
+    game_version: u16,
+    binary_version: u16,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DeleteListForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    #[serde(rename = "listID")]
+    list_id: ListId,
+    secret: &'f str,
+}
+
+impl ListQuery<'_> {
+    fn form<'f>(&'f self, auth: Option<AuthForm>) -> SearchListsForm<'f> {
+        let mut difficulties: Vec<i8> = self
+            .difficulties
+            .iter()
+            .copied()
+            .map(difficulty_filter)
+            .collect();
+
+        difficulties.sort_unstable();
+        difficulties.dedup();
+
+        SearchListsForm {
+            search_type: self.search_type.value(),
+            query: self.query.as_deref().unwrap_or_default(),
+            diff: or_any(join(difficulties, |difficulty| difficulty.to_string())),
+            demon_filter: self.difficulties.iter().copied().find_map(demon_filter),
+            star: self.rated.and_then(flag),
+            no_star: self.rated.and_then(|rated| flag(!rated)),
+            followed: (!self.followed.is_empty())
+                .then(|| join(&self.followed, |account_id| account_id.to_string())),
+            page: self.page,
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            auth,
+            secret: secrets::COMMON,
+        }
+    }
+}
+
+pub const fn difficulty_value(difficulty: Difficulty) -> i8 {
+    match difficulty {
+        Difficulty::NotAvailable => NOT_AVAILABLE,
+        Difficulty::Auto => 0,
+        Difficulty::Easy => 1,
+        Difficulty::Normal => 2,
+        Difficulty::Hard => 3,
+        Difficulty::Harder => 4,
+        Difficulty::Insane => 5,
+        Difficulty::EasyDemon => 6,
+        Difficulty::MediumDemon => 7,
+        Difficulty::HardDemon => 8,
+        Difficulty::InsaneDemon => 9,
+        Difficulty::ExtremeDemon => 10,
+    }
+}
+
+pub(crate) fn parse_list_difficulty(map: &Map<'_>) -> Result<Difficulty, robtop::Error> {
+    let value: i8 = map
+        .parse_optional(keys::DIFFICULTY)?
+        .unwrap_or(NOT_AVAILABLE);
+
+    let difficulty = match value {
+        NOT_AVAILABLE => Difficulty::NotAvailable,
+        0 => Difficulty::Auto,
+        1 => Difficulty::Easy,
+        2 => Difficulty::Normal,
+        3 => Difficulty::Hard,
+        4 => Difficulty::Harder,
+        5 => Difficulty::Insane,
+        6 => Difficulty::EasyDemon,
+        7 => Difficulty::MediumDemon,
+        8 => Difficulty::HardDemon,
+        9 => Difficulty::InsaneDemon,
+        10 => Difficulty::ExtremeDemon,
+        _ => return Err(InvalidError::new(keys::DIFFICULTY, value.to_string()).into()),
+    };
+
+    Ok(difficulty)
+}
+
+pub(crate) fn parse_timestamp(map: &Map<'_>, key: &str) -> Result<Option<Instant>, robtop::Error> {
+    let seconds: Option<i64> = map.parse_optional(key)?;
+
+    Ok(seconds.and_then(Instant::from_seconds))
+}
+
+pub(crate) fn parse_list_map(
+    map: &Map<'_>,
+    creators: &HashMap<UserId, UserReference<'static>>,
+) -> Result<LevelList<'static>, robtop::Error> {
+    let account_id = AccountId::new(map.parse_or_default(keys::CREATOR_ACCOUNT_ID)?);
+
+    // lists only carry the creator account ID, so the user ID comes from the creators section
+    let id = creators
+        .values()
+        .find(|creator| creator.account_id == account_id)
+        .map(|creator| creator.id)
+        .unwrap_or_default();
+
+    let creator = UserReference::builder()
+        .id(id)
+        .name(owned(map.get(keys::CREATOR_NAME).unwrap_or_default()))
+        .account_id(account_id)
+        .build();
+
+    let list = LevelList::builder()
+        .id(ListId::new(map.parse_required(keys::ID)?))
+        .name(owned(map.get(keys::NAME).unwrap_or_default()))
+        .description(parse_base64(map, keys::DESCRIPTION)?)
+        .creator(creator)
+        .level_ids(parse_level_ids(map, keys::LEVEL_IDS)?)
+        .version(Version::new(map.parse_or_default(keys::VERSION)?))
+        .difficulty(parse_list_difficulty(map)?)
+        .downloads(Downloads::new(map.parse_or_default(keys::DOWNLOADS)?))
+        .rating(Rating::new(map.parse_or_default(keys::RATING)?))
+        .reward(Diamonds::new(map.parse_or_default(keys::REWARD)?))
+        .reward_threshold(Levels::new(map.parse_or_default(keys::REWARD_THRESHOLD)?))
+        .featured(map.parse_bool(keys::FEATURED))
+        .maybe_created_at(parse_timestamp(map, keys::CREATED_AT)?)
+        .maybe_updated_at(parse_timestamp(map, keys::UPDATED_AT)?)
+        .build();
+
+    Ok(list)
+}
+
+pub(crate) fn parse_lists(
+    string: &str,
+    creators: &str,
+) -> Result<Vec<LevelList<'static>>, robtop::Error> {
+    let creators = parse_creators(creators)?;
+
+    string
+        .split(pages::ITEM_DELIMITER)
+        .filter(|item| !item.is_empty())
+        .map(|item| parse_list_map(&Map::parse(item, DELIMITER), &creators))
+        .collect()
+}
+
+impl<S: State> Client<S> {
+    pub async fn search_lists(
+        &self,
+        query: &ListQuery<'_>,
+    ) -> Result<Page<LevelList<'static>>, SearchListsError> {
+        let form = query.form(self.state.auth().map(|auth| auth.form()));
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::SEARCH_LISTS, &form)
+            .await?;
+
+        let response = response.trim();
+
+        if response == NO_RESULTS {
+            return Ok(Page::empty());
+        }
+
+        let mut sections = response.split(pages::SECTION_DELIMITER);
+
+        let mut next = || sections.next().unwrap_or_default();
+
+        let lists = next();
+        let creators = next();
+        let trailer = next();
+
+        let page = pages::parse_page(parse_lists(lists, creators)?, trailer)?;
+
+        Ok(page)
+    }
+
+    pub async fn get_list(&self, id: ListId) -> Result<LevelList<'static>, GetListError> {
+        let query = ListQuery::builder()
+            .query(Str::Owned(id.to_string()))
+            .build();
+
+        self.search_lists(&query)
+            .await?
+            .items
+            .into_iter()
+            .find(|list| list.id == id)
+            .ok_or(GetListError::NotFound)
+    }
+}
+
+impl Authenticated<'_> {
+    pub async fn upload_list(&self, upload: ListUpload<'_>) -> Result<ListId, UploadListError> {
+        let levels = join(&upload.level_ids, |id| id.to_string());
+
+        let form = UploadListForm {
+            auth: self.state.auth.form(),
+            list_id: upload.id,
+            list_name: upload.name.as_ref(),
+            list_desc: base64::encode(upload.description.as_ref()),
+            list_levels: &levels,
+            difficulty: difficulty_value(upload.difficulty),
+            original: upload.original_id,
+            unlisted: flag(upload.unlisted),
+            list_version: upload.version,
+            // unlike level uploads, lists send the sampled check first and the random string second
+            seed: check::of_sampled_with_salt(&levels, salts::LEVEL, crypto_keys::LEVEL),
+            seed2: random::seed_string(),
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::UPLOAD_LIST, &form)
+            .await?;
+
+        response
+            .trim()
+            .parse()
+            .ok()
+            .filter(|&id| id > 0)
+            .map(ListId::new)
+            .ok_or(UploadListError::Failed)
+    }
+
+    pub async fn delete_list(&self, list_id: ListId) -> Result<(), DeleteListError> {
+        let form = DeleteListForm {
+            auth: self.state.auth.form(),
+            list_id,
+            secret: secrets::DELETE,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::DELETE_LIST, &form)
+            .await?;
+
+        if response.trim() != SUCCESS {
+            return Err(DeleteListError::Failed);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use gd_core::types::{
+    id::MapPackId,
+    reward::{RewardCoins, RewardStars},
+};
+use gd_entities::map_packs::MapPack;
+use gd_enums::levels::Difficulty;
+use gd_internals::{
+    http::RequestError,
+    robtop::{self, Map},
+};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{
+    client::Client,
+    endpoints,
+    pages::{self, Page},
+    secrets,
+    state::State,
+    utils::{owned, parse_color, parse_level_ids},
+    versions,
+};
+
+pub const DELIMITER: &str = ":";
+
+pub const NO_RESULTS: &str = "-1";
+
+pub(crate) mod keys {
+    pub const ID: &str = "1";
+    pub const NAME: &str = "2";
+    pub const LEVEL_IDS: &str = "3";
+    pub const STARS: &str = "4";
+    pub const COINS: &str = "5";
+    pub const DIFFICULTY: &str = "6";
+    pub const COLOR: &str = "7";
+    pub const BAR_COLOR: &str = "8";
+}
+
+pub mod difficulties {
+    pub const AUTO: u8 = 0;
+    pub const EASY: u8 = 1;
+    pub const NORMAL: u8 = 2;
+    pub const HARD: u8 = 3;
+    pub const HARDER: u8 = 4;
+    pub const INSANE: u8 = 5;
+    pub const HARD_DEMON: u8 = 6;
+    pub const EASY_DEMON: u8 = 7;
+    pub const MEDIUM_DEMON: u8 = 8;
+    pub const INSANE_DEMON: u8 = 9;
+    pub const EXTREME_DEMON: u8 = 10;
+}
+
+#[derive(Debug, Error)]
+pub enum GetMapPacksError {
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GetMapPacksForm<'f> {
+    page: u32,
+    game_version: u16,
+    binary_version: u16,
+    secret: &'f str,
+}
+
+pub const fn difficulty(value: u8) -> Difficulty {
+    match value {
+        difficulties::AUTO => Difficulty::Auto,
+        difficulties::EASY => Difficulty::Easy,
+        difficulties::NORMAL => Difficulty::Normal,
+        difficulties::HARD => Difficulty::Hard,
+        difficulties::HARDER => Difficulty::Harder,
+        difficulties::INSANE => Difficulty::Insane,
+        difficulties::HARD_DEMON => Difficulty::HardDemon,
+        difficulties::EASY_DEMON => Difficulty::EasyDemon,
+        difficulties::MEDIUM_DEMON => Difficulty::MediumDemon,
+        difficulties::INSANE_DEMON => Difficulty::InsaneDemon,
+        difficulties::EXTREME_DEMON => Difficulty::ExtremeDemon,
+        _ => Difficulty::NotAvailable,
+    }
+}
+
+pub(crate) fn parse_map_pack(string: &str) -> Result<MapPack<'static>, robtop::Error> {
+    let map = Map::parse(string, DELIMITER);
+
+    let map_pack = MapPack::builder()
+        .id(MapPackId::new(map.parse_required(keys::ID)?))
+        .name(owned(map.get(keys::NAME).unwrap_or_default()))
+        .level_ids(parse_level_ids(&map, keys::LEVEL_IDS)?)
+        .stars(RewardStars::new(map.parse_or_default(keys::STARS)?))
+        .coins(RewardCoins::new(map.parse_or_default(keys::COINS)?))
+        .difficulty(difficulty(map.parse_or_default(keys::DIFFICULTY)?))
+        .color(parse_color(&map, keys::COLOR)?)
+        .bar_color(parse_color(&map, keys::BAR_COLOR)?)
+        .build();
+
+    Ok(map_pack)
+}
+
+impl<S: State> Client<S> {
+    pub async fn get_map_packs(
+        &self,
+        page: u32,
+    ) -> Result<Page<MapPack<'static>>, GetMapPacksError> {
+        let form = GetMapPacksForm {
+            page,
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::GET_MAP_PACKS, &form)
+            .await?;
+
+        let response = response.trim();
+
+        if response == NO_RESULTS {
+            return Ok(Page::empty());
+        }
+
+        let mut sections = response.split(pages::SECTION_DELIMITER);
+
+        let mut next = || sections.next().unwrap_or_default();
+
+        let map_packs = next();
+        let trailer = next();
+
+        let map_packs = map_packs
+            .split(pages::ITEM_DELIMITER)
+            .filter(|item| !item.is_empty())
+            .map(parse_map_pack)
+            .collect::<Result<_, _>>()?;
+
+        let page = pages::parse_page(map_packs, trailer)?;
+
+        Ok(page)
+    }
+}
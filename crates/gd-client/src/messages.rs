@@ -0,0 +1,299 @@
+use std::borrow::Cow;
+
+use gd_core::types::id::{AccountId, MessageId, UserId};
+use gd_crypto::{base64, keys as crypto_keys, robtop as crypto_robtop};
+use gd_entities::{messages::Message, users::UserReference};
+use gd_enums::messages::Type;
+use gd_internals::{
+    http::RequestError,
+    robtop::{self, InvalidError, Map},
+};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{
+    auth::AuthForm,
+    client::Authenticated,
+    endpoints,
+    pages::{self, Page},
+    secrets,
+    utils::{flag, owned, parse_base64, parse_instant},
+};
+
+pub const DELIMITER: &str = ":";
+
+pub const LIST_DELIMITER: &str = ",";
+
+pub const NO_RESULTS: &str = "-2";
+
+pub const NOT_FOUND: &str = "-1";
+
+pub const SUCCESS: &str = "1";
+
+pub(crate) mod keys {
+    pub const ID: &str = "1";
+    pub const ACCOUNT_ID: &str = "2";
+    pub const USER_ID: &str = "3";
+    pub const SUBJECT: &str = "4";
+    pub const CONTENT: &str = "5";
+    pub const NAME: &str = "6";
+    pub const CREATED_AT: &str = "7";
+    pub const READ: &str = "8";
+    pub const OUTGOING: &str = "9";
+}
+
+#[derive(Debug, Error)]
+pub enum GetMessagesError {
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum GetMessageError {
+    #[error("message not found")]
+    NotFound,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SendMessageError {
+    #[error("failed to send message")]
+    Failed,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+}
+
+#[derive(Debug, Error)]
+pub enum DeleteMessagesError {
+    #[error("failed to delete messages")]
+    Failed,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GetMessagesForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    page: u32,
+    total: u32,
+    get_sent: Option<u8>,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GetMessageForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    #[serde(rename = "messageID")]
+    message_id: MessageId,
+    is_sender: Option<u8>,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SendMessageForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    #[serde(rename = "toAccountID")]
+    to_account_id: AccountId,
+    subject: String,
+    body: String,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DeleteMessagesForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    messages: String,
+    is_sender: Option<u8>,
+    secret: &'f str,
+}
+
+pub(crate) fn parse_content(map: &Map<'_>) -> Result<Option<Cow<'static, str>>, robtop::Error> {
+    let Some(value) = map.get(keys::CONTENT) else {
+        return Ok(None);
+    };
+
+    let content = crypto_robtop::decode_string(value, crypto_keys::MESSAGE)
+        .map_err(|_| InvalidError::new(keys::CONTENT, value))?;
+
+    Ok(Some(Cow::Owned(content)))
+}
+
+pub(crate) fn parse_message(string: &str) -> Result<Message<'static>, robtop::Error> {
+    let map = Map::parse(string, DELIMITER);
+
+    let user = UserReference::builder()
+        .id(UserId::new(map.parse_or_default(keys::USER_ID)?))
+        .name(owned(map.get(keys::NAME).unwrap_or_default()))
+        .account_id(AccountId::new(map.parse_or_default(keys::ACCOUNT_ID)?))
+        .build();
+
+    let message_type = if map.parse_bool(keys::OUTGOING) {
+        Type::Outgoing
+    } else {
+        Type::Incoming
+    };
+
+    let message = Message::builder()
+        .id(MessageId::new(map.parse_required(keys::ID)?))
+        .user(user)
+        .message_type(message_type)
+        .subject(parse_base64(&map, keys::SUBJECT)?)
+        .maybe_content(parse_content(&map)?)
+        .maybe_created_at(parse_instant(&map, keys::CREATED_AT))
+        .read(map.parse_bool(keys::READ))
+        .build();
+
+    Ok(message)
+}
+
+pub(crate) fn parse_messages(string: &str) -> Result<Vec<Message<'static>>, robtop::Error> {
+    string
+        .split(pages::ITEM_DELIMITER)
+        .filter(|item| !item.is_empty())
+        .map(parse_message)
+        .collect()
+}
+
+impl Authenticated<'_> {
+    pub async fn get_messages(
+        &self,
+        message_type: Type,
+        page: u32,
+    ) -> Result<Page<Message<'static>>, GetMessagesError> {
+        let form = GetMessagesForm {
+            auth: self.state.auth.form(),
+            page,
+            total: 0,
+            get_sent: flag(message_type.is_outgoing()),
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::GET_MESSAGES, &form)
+            .await?;
+
+        let response = response.trim();
+
+        if response == NO_RESULTS {
+            return Ok(Page::empty());
+        }
+
+        let (messages, trailer) = response
+            .split_once(pages::SECTION_DELIMITER)
+            .unwrap_or((response, ""));
+
+        let page = pages::parse_page(parse_messages(messages)?, trailer)?;
+
+        Ok(page)
+    }
+
+    pub async fn get_message(
+        &self,
+        message_id: MessageId,
+        message_type: Type,
+    ) -> Result<Message<'static>, GetMessageError> {
+        let form = GetMessageForm {
+            auth: self.state.auth.form(),
+            message_id,
+            is_sender: flag(message_type.is_outgoing()),
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::GET_MESSAGE, &form)
+            .await?;
+
+        let response = response.trim();
+
+        if response == NOT_FOUND {
+            return Err(GetMessageError::NotFound);
+        }
+
+        let message = parse_message(response)?;
+
+        Ok(message)
+    }
+
+    pub async fn send_message(
+        &self,
+        account_id: AccountId,
+        subject: &str,
+        content: &str,
+    ) -> Result<(), SendMessageError> {
+        let form = SendMessageForm {
+            auth: self.state.auth.form(),
+            to_account_id: account_id,
+            subject: base64::encode(subject),
+            body: crypto_robtop::encode(content, crypto_keys::MESSAGE),
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::SEND_MESSAGE, &form)
+            .await?;
+
+        if response.trim() != SUCCESS {
+            return Err(SendMessageError::Failed);
+        }
+
+        Ok(())
+    }
+
+    pub async fn delete_message(
+        &self,
+        message_id: MessageId,
+        message_type: Type,
+    ) -> Result<(), DeleteMessagesError> {
+        self.delete_messages(&[message_id], message_type).await
+    }
+
+    pub async fn delete_messages(
+        &self,
+        message_ids: &[MessageId],
+        message_type: Type,
+    ) -> Result<(), DeleteMessagesError> {
+        let messages = message_ids
+            .iter()
+            .map(MessageId::to_string)
+            .collect::<Vec<_>>()
+            .join(LIST_DELIMITER);
+
+        let form = DeleteMessagesForm {
+            auth: self.state.auth.form(),
+            messages,
+            is_sender: flag(message_type.is_outgoing()),
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::DELETE_MESSAGES, &form)
+            .await?;
+
+        if response.trim() != SUCCESS {
+            return Err(DeleteMessagesError::Failed);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,270 @@
+use gd_core::types::{
+    id::{LevelId, RoleId, UserId},
+    reward::RewardStars,
+};
+use gd_crypto::{check, keys as crypto_keys, random, salts};
+use gd_enums::levels::Difficulty;
+use gd_internals::http::RequestError;
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{
+    auth::AuthForm,
+    client::{Authenticated, Client},
+    endpoints,
+    levels::demon_filter,
+    secrets,
+    state::State,
+    utils::flag,
+    versions,
+};
+
+pub const SUCCESS: &str = "1";
+
+pub const FAILED: &str = "-1";
+
+pub const NO_PERMISSION: &str = "-2";
+
+pub mod roles {
+    pub const NONE: u8 = 0;
+    pub const MODERATOR: u8 = 1;
+    pub const ELDER_MODERATOR: u8 = 2;
+}
+
+pub mod modes {
+    pub const VOTE: u8 = 0;
+    pub const MODERATOR: u8 = 1;
+}
+
+#[derive(Debug, Error)]
+pub enum ModerationError {
+    #[error("missing moderator permissions")]
+    NoPermission,
+    #[error("level is not a demon")]
+    NotDemon,
+    #[error("moderation action failed")]
+    Failed,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+}
+
+impl ModerationError {
+    pub fn from_response(response: &str) -> Self {
+        match response {
+            NO_PERMISSION => Self::NoPermission,
+            _ => Self::Failed,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RateStarsForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    udid: String,
+    uuid: UserId,
+    #[serde(rename = "levelID")]
+    level_id: LevelId,
+    stars: RewardStars,
+    rs: String,
+    chk: String,
+    game_version: u16,
+    binary_version: u16,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SuggestStarsForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    #[serde(rename = "levelID")]
+    level_id: LevelId,
+    stars: RewardStars,
+    feature: u8,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RateDemonForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    #[serde(rename = "levelID")]
+    level_id: LevelId,
+    rating: u8,
+    mode: u8,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportLevelForm<'f> {
+    #[serde(rename = "levelID")]
+    level_id: LevelId,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestModeratorForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    secret: &'f str,
+}
+
+pub fn parse_role(response: &str) -> Option<RoleId> {
+    response
+        .parse()
+        .ok()
+        .filter(|role| (roles::MODERATOR..=roles::ELDER_MODERATOR).contains(role))
+        .map(RoleId::new)
+}
+
+impl<S: State> Client<S> {
+    pub async fn report_level(&self, level_id: LevelId) -> Result<(), ModerationError> {
+        let form = ReportLevelForm {
+            level_id,
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::REPORT_LEVEL, &form)
+            .await?;
+
+        let response = response.trim();
+
+        if response == FAILED {
+            return Err(ModerationError::Failed);
+        }
+
+        Ok(())
+    }
+}
+
+impl Authenticated<'_> {
+    pub async fn rate_stars(
+        &self,
+        level_id: LevelId,
+        stars: RewardStars,
+    ) -> Result<(), ModerationError> {
+        let auth = &self.state.auth;
+
+        let account_id = auth.reference.account_id;
+        let user_id = auth.reference.id;
+
+        let udid = random::udid();
+        let rs = random::seed_string();
+
+        let chk = check::of_with_salt(
+            format!("{level_id}{stars}{rs}{account_id}{udid}{user_id}"),
+            salts::RATE,
+            crypto_keys::RATE,
+        );
+
+        let form = RateStarsForm {
+            auth: auth.form(),
+            udid,
+            uuid: user_id,
+            level_id,
+            stars,
+            rs,
+            chk,
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            secret: secrets::COMMON,
+        };
+
+        let response = self.session.http.post(endpoints::RATE_STARS, &form).await?;
+
+        let response = response.trim();
+
+        if response != SUCCESS {
+            return Err(ModerationError::from_response(response));
+        }
+
+        Ok(())
+    }
+
+    pub async fn suggest_stars(
+        &self,
+        level_id: LevelId,
+        stars: RewardStars,
+        feature: bool,
+    ) -> Result<(), ModerationError> {
+        let form = SuggestStarsForm {
+            auth: self.state.auth.form(),
+            level_id,
+            stars,
+            feature: flag(feature).unwrap_or_default(),
+            secret: secrets::MODERATOR,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::SUGGEST_STARS, &form)
+            .await?;
+
+        let response = response.trim();
+
+        if response != SUCCESS {
+            return Err(ModerationError::from_response(response));
+        }
+
+        Ok(())
+    }
+
+    pub async fn rate_demon(
+        &self,
+        level_id: LevelId,
+        difficulty: Difficulty,
+        as_moderator: bool,
+    ) -> Result<(), ModerationError> {
+        let rating = demon_filter(difficulty).ok_or(ModerationError::NotDemon)?;
+
+        let mode = if as_moderator {
+            modes::MODERATOR
+        } else {
+            modes::VOTE
+        };
+
+        let form = RateDemonForm {
+            auth: self.state.auth.form(),
+            level_id,
+            rating,
+            mode,
+            secret: secrets::MODERATOR,
+        };
+
+        let response = self.session.http.post(endpoints::RATE_DEMON, &form).await?;
+
+        let response = response.trim();
+
+        if response.parse::<u64>().is_err() {
+            return Err(ModerationError::from_response(response));
+        }
+
+        Ok(())
+    }
+
+    pub async fn request_moderator(&self) -> Result<RoleId, ModerationError> {
+        let form = RequestModeratorForm {
+            auth: self.state.auth.form(),
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::REQUEST_MODERATOR, &form)
+            .await?;
+
+        let response = response.trim();
+
+        parse_role(response).ok_or_else(|| ModerationError::from_response(response))
+    }
+}
@@ -0,0 +1,57 @@
+use gd_internals::robtop::{self, InvalidError};
+
+pub const SECTION_DELIMITER: &str = "#";
+
+pub const ITEM_DELIMITER: &str = "|";
+
+pub const PAGE_DELIMITER: &str = ":";
+
+pub const PAGE_KEY: &str = "page";
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: u32,
+    pub offset: u32,
+    pub size: u32,
+}
+
+impl<T> Page<T> {
+    pub const fn new(items: Vec<T>, total: u32, offset: u32, size: u32) -> Self {
+        Self {
+            items,
+            total,
+            offset,
+            size,
+        }
+    }
+
+    pub const fn empty() -> Self {
+        Self::new(Vec::new(), 0, 0, 0)
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub const fn has_next(&self) -> bool {
+        self.offset as usize + self.items.len() < self.total as usize
+    }
+}
+
+pub(crate) fn parse_page<T>(items: Vec<T>, trailer: &str) -> Result<Page<T>, robtop::Error> {
+    let values = trailer
+        .trim()
+        .split(PAGE_DELIMITER)
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| InvalidError::new(PAGE_KEY, value))
+        })
+        .collect::<Result<Vec<u32>, _>>()?;
+
+    let nth = |index: usize| values.get(index).copied().unwrap_or_default();
+
+    Ok(Page::new(items, nth(0), nth(1), nth(2)))
+}
@@ -0,0 +1,257 @@
+use gd_core::types::{
+    id::{ColorId, IconId, LevelId, LongIconId, UserId},
+    info::{LevelInfo, PlatformerInfo},
+    statistics::{Demons, Diamonds, Levels, Moons, SecretCoins, Stars, UserCoins},
+};
+use gd_crypto::{check, keys as crypto_keys, random, salts};
+use gd_entities::users::{UserCosmetics, UserSocials, UserStates, UserStatistics};
+use gd_internals::http::RequestError;
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{auth::AuthForm, client::Authenticated, endpoints, levels::join, secrets, versions};
+
+pub const SUCCESS: &str = "1";
+
+#[derive(Debug, Error)]
+pub enum UpdateProfileError {
+    #[error("failed to update profile")]
+    Failed,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+}
+
+#[derive(Debug, Error)]
+pub enum UpdateSettingsError {
+    #[error("failed to update settings")]
+    Failed,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateProfileForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    user_name: &'f str,
+    stars: Stars,
+    moons: Moons,
+    demons: Demons,
+    diamonds: Diamonds,
+    coins: SecretCoins,
+    user_coins: UserCoins,
+    icon: LongIconId,
+    icon_type: u8,
+    color1: ColorId,
+    color2: ColorId,
+    color3: ColorId,
+    special: IconId,
+    acc_icon: LongIconId,
+    acc_ship: IconId,
+    acc_ball: IconId,
+    acc_bird: IconId,
+    acc_dart: IconId,
+    acc_robot: IconId,
+    acc_glow: u8,
+    acc_spider: IconId,
+    acc_explosion: IconId,
+    acc_swing: IconId,
+    acc_jetpack: IconId,
+    dinfo: Option<String>,
+    dinfow: Option<Demons>,
+    dinfog: Option<Demons>,
+    sinfo: String,
+    sinfod: Levels,
+    sinfog: Levels,
+    seed: String,
+    seed2: String,
+    game_version: u16,
+    binary_version: u16,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+struct UpdateSettingsForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    #[serde(rename = "mS")]
+    message_state: u8,
+    #[serde(rename = "frS")]
+    friend_request_state: u8,
+    #[serde(rename = "cS")]
+    comment_state: u8,
+    #[serde(rename = "yt")]
+    youtube: &'f str,
+    #[serde(rename = "twitter")]
+    x: &'f str,
+    twitch: &'f str,
+    discord: &'f str,
+    secret: &'f str,
+}
+
+fn level_counts(level_info: LevelInfo, platformer_info: PlatformerInfo) -> String {
+    let LevelInfo {
+        auto,
+        easy,
+        normal,
+        hard,
+        harder,
+        insane,
+        ..
+    } = level_info;
+
+    let classic = [auto, easy, normal, hard, harder, insane];
+
+    let PlatformerInfo {
+        auto,
+        easy,
+        normal,
+        hard,
+        harder,
+        insane,
+    } = platformer_info;
+
+    let platformer = [auto, easy, normal, hard, harder, insane];
+
+    join(classic.into_iter().chain(platformer), |levels| {
+        levels.get().to_string()
+    })
+}
+
+impl Authenticated<'_> {
+    pub async fn update_profile(
+        &self,
+        statistics: &UserStatistics,
+        cosmetics: &UserCosmetics,
+        completed_demons: Option<&[LevelId]>,
+    ) -> Result<UserId, UpdateProfileError> {
+        let auth = &self.state.auth;
+
+        let account_id = auth.reference.account_id;
+
+        let special = statistics.demon_info.special;
+
+        // servers rebuild the demon breakdown from these, so they are only sent when known
+        let dinfo = completed_demons.map(|ids| join(ids, |id| id.to_string()));
+        let (dinfow, dinfog) = completed_demons
+            .map(|_| (special.weekly, special.gauntlet))
+            .unzip();
+
+        let sinfo = level_counts(statistics.level_info, statistics.platformer_info);
+
+        let seed2 = check::of_with_salt(
+            format!(
+                "{account_id}{user_coins}{demons}{stars}{coins}{icon_type}{icon}{diamonds}\
+                {acc_icon}{acc_ship}{acc_ball}{acc_bird}{acc_dart}{acc_robot}{acc_glow}\
+                {acc_spider}{acc_explosion}{dinfo_length}{dinfow}{dinfog}{sinfo}{sinfod}{sinfog}",
+                user_coins = statistics.user_coins,
+                demons = statistics.demons,
+                stars = statistics.stars,
+                coins = statistics.secret_coins,
+                icon_type = cosmetics.icon_type.value(),
+                icon = cosmetics.icon_id,
+                diamonds = statistics.diamonds,
+                acc_icon = cosmetics.cube_id,
+                acc_ship = cosmetics.ship_id,
+                acc_ball = cosmetics.ball_id,
+                acc_bird = cosmetics.ufo_id,
+                acc_dart = cosmetics.wave_id,
+                acc_robot = cosmetics.robot_id,
+                acc_glow = u8::from(cosmetics.glow),
+                acc_spider = cosmetics.spider_id,
+                acc_explosion = cosmetics.explosion_id,
+                dinfo_length = dinfo.as_ref().map_or(0, String::len),
+                dinfow = dinfow.unwrap_or_default(),
+                dinfog = dinfog.unwrap_or_default(),
+                sinfod = statistics.level_info.daily,
+                sinfog = statistics.level_info.gauntlet,
+            ),
+            salts::PROFILE,
+            crypto_keys::PROFILE,
+        );
+
+        let form = UpdateProfileForm {
+            auth: auth.form(),
+            user_name: auth.credentials.name.as_ref(),
+            stars: statistics.stars,
+            moons: statistics.moons,
+            demons: statistics.demons,
+            diamonds: statistics.diamonds,
+            coins: statistics.secret_coins,
+            user_coins: statistics.user_coins,
+            icon: cosmetics.icon_id,
+            icon_type: cosmetics.icon_type.value(),
+            color1: cosmetics.color_1_id,
+            color2: cosmetics.color_2_id,
+            color3: cosmetics.color_3_id,
+            special: cosmetics.streak_id,
+            acc_icon: cosmetics.cube_id,
+            acc_ship: cosmetics.ship_id,
+            acc_ball: cosmetics.ball_id,
+            acc_bird: cosmetics.ufo_id,
+            acc_dart: cosmetics.wave_id,
+            acc_robot: cosmetics.robot_id,
+            acc_glow: u8::from(cosmetics.glow),
+            acc_spider: cosmetics.spider_id,
+            acc_explosion: cosmetics.explosion_id,
+            acc_swing: cosmetics.swing_id,
+            acc_jetpack: cosmetics.jetpack_id,
+            dinfo,
+            dinfow,
+            dinfog,
+            sinfo,
+            sinfod: statistics.level_info.daily,
+            sinfog: statistics.level_info.gauntlet,
+            seed: random::seed_string(),
+            seed2,
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::UPDATE_PROFILE, &form)
+            .await?;
+
+        response
+            .trim()
+            .parse()
+            .ok()
+            .filter(|&id| id > 0)
+            .map(UserId::new)
+            .ok_or(UpdateProfileError::Failed)
+    }
+
+    pub async fn update_settings(
+        &self,
+        states: &UserStates,
+        socials: &UserSocials<'_>,
+    ) -> Result<(), UpdateSettingsError> {
+        let form = UpdateSettingsForm {
+            auth: self.state.auth.form(),
+            message_state: states.messages.value(),
+            friend_request_state: states.friend_requests.value(),
+            comment_state: states.comments.value(),
+            youtube: socials.youtube.as_deref().unwrap_or_default(),
+            x: socials.x.as_deref().unwrap_or_default(),
+            twitch: socials.twitch.as_deref().unwrap_or_default(),
+            discord: socials.discord.as_deref().unwrap_or_default(),
+            secret: secrets::ACCOUNT,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::UPDATE_SETTINGS, &form)
+            .await?;
+
+        if response.trim() != SUCCESS {
+            return Err(UpdateSettingsError::Failed);
+        }
+
+        Ok(())
+    }
+}
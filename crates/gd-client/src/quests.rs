@@ -0,0 +1,165 @@
+use gd_core::types::{
+    id::{QuestId, UserId},
+    statistics::Diamonds,
+    str::Str,
+    time::Duration,
+};
+use gd_crypto::{check, keys as crypto_keys, random, robtop as crypto_robtop, salts};
+use gd_enums::quests::Type;
+use gd_internals::{
+    http::RequestError,
+    robtop::{self, InvalidError},
+};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{
+    auth::AuthForm,
+    client::Authenticated,
+    endpoints, secrets,
+    utils::{generate_chk, owned, parse_at, seconds, split_hashed},
+    versions,
+};
+
+pub const DELIMITER: &str = ":";
+
+pub const QUEST_DELIMITER: &str = ",";
+
+pub const FAILED: &str = "-1";
+
+pub const LEFT_INDEX: usize = 5;
+
+pub const QUESTS_OFFSET: usize = 6;
+
+pub(crate) mod keys {
+    pub const LEFT: &str = "left";
+    pub const ID: &str = "id";
+    pub const TYPE: &str = "type";
+    pub const AMOUNT: &str = "amount";
+    pub const REWARD: &str = "reward";
+}
+
+pub mod indices {
+    pub const ID: usize = 0;
+    pub const TYPE: usize = 1;
+    pub const AMOUNT: usize = 2;
+    pub const REWARD: usize = 3;
+    pub const NAME: usize = 4;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Quest<'q> {
+    pub id: QuestId,
+    pub quest_type: Type,
+    pub amount: u32,
+    pub reward: Diamonds,
+    pub name: Str<'q>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Quests<'q> {
+    pub quests: Vec<Quest<'q>>,
+    pub left: Duration,
+}
+
+#[derive(Debug, Error)]
+pub enum QuestsError {
+    #[error("failed to get quests")]
+    Failed,
+    #[error("quests hash mismatch")]
+    Mismatch,
+    #[error(transparent)]
+    Decode(#[from] crypto_robtop::Error),
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct QuestsForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    udid: &'f str,
+    uuid: UserId,
+    chk: String,
+    game_version: u16,
+    binary_version: u16,
+    secret: &'f str,
+}
+
+pub(crate) fn parse_quest(string: &str) -> Result<Quest<'static>, robtop::Error> {
+    let values: Vec<&str> = string.split(QUEST_DELIMITER).collect();
+
+    let type_value: u8 = parse_at(&values, indices::TYPE, keys::TYPE)?;
+
+    let quest_type = Type::from_value(type_value)
+        .ok_or_else(|| InvalidError::new(keys::TYPE, type_value.to_string()))?;
+
+    let quest = Quest {
+        id: QuestId::new(parse_at(&values, indices::ID, keys::ID)?),
+        quest_type,
+        amount: parse_at(&values, indices::AMOUNT, keys::AMOUNT)?,
+        reward: Diamonds::new(parse_at(&values, indices::REWARD, keys::REWARD)?),
+        name: owned(values.get(indices::NAME).copied().unwrap_or_default()),
+    };
+
+    Ok(quest)
+}
+
+pub(crate) fn parse_quests(string: &str) -> Result<Quests<'static>, robtop::Error> {
+    let values: Vec<&str> = string.split(DELIMITER).collect();
+
+    let left: u64 = parse_at(&values, LEFT_INDEX, keys::LEFT)?;
+
+    let quests = values
+        .iter()
+        .skip(QUESTS_OFFSET)
+        .filter(|value| !value.is_empty())
+        .map(|value| parse_quest(value))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Quests {
+        quests,
+        left: seconds(left),
+    })
+}
+
+impl Authenticated<'_> {
+    pub async fn get_quests(&self) -> Result<Quests<'static>, QuestsError> {
+        let auth = &self.state.auth;
+
+        let udid = random::udid();
+
+        let form = QuestsForm {
+            auth: auth.form(),
+            udid: &udid,
+            uuid: auth.reference.id,
+            chk: generate_chk(crypto_keys::QUESTS),
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            secret: secrets::COMMON,
+        };
+
+        let response = self.session.http.post(endpoints::GET_QUESTS, &form).await?;
+
+        let response = response.trim();
+
+        if response == FAILED {
+            return Err(QuestsError::Failed);
+        }
+
+        let (encoded, hash) = split_hashed(response).ok_or(QuestsError::Failed)?;
+
+        if !check::verify_with_salt(encoded, salts::QUESTS, hash) {
+            return Err(QuestsError::Mismatch);
+        }
+
+        let decoded = crypto_robtop::decode_string(encoded, crypto_keys::QUESTS)?;
+
+        let quests = parse_quests(&decoded)?;
+
+        Ok(quests)
+    }
+}
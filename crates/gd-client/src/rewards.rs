@@ -0,0 +1,214 @@
+use gd_core::types::{
+    id::UserId,
+    statistics::{Diamonds, Orbs},
+    time::Duration,
+};
+use gd_crypto::{check, keys as crypto_keys, random, robtop as crypto_robtop, salts};
+use gd_enums::rewards::{Chest, Item};
+use gd_internals::{
+    http::RequestError,
+    robtop::{self, InvalidError},
+};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{
+    auth::AuthForm,
+    client::Authenticated,
+    endpoints, secrets,
+    utils::{generate_chk, parse_at, seconds, split_hashed},
+    versions,
+};
+
+pub const DELIMITER: &str = ":";
+
+pub const CONTENT_DELIMITER: &str = ",";
+
+pub const FAILED: &str = "-1";
+
+pub const NONE: u8 = 0;
+
+pub const NO_ITEM: u8 = 0;
+
+pub(crate) mod keys {
+    pub const LEFT: &str = "left";
+    pub const OPENED: &str = "opened";
+    pub const ORBS: &str = "orbs";
+    pub const DIAMONDS: &str = "diamonds";
+    pub const ITEM: &str = "item";
+}
+
+pub mod offsets {
+    pub const SMALL: usize = 5;
+    pub const LARGE: usize = 8;
+}
+
+pub mod indices {
+    pub const LEFT: usize = 0;
+    pub const CONTENT: usize = 1;
+    pub const OPENED: usize = 2;
+}
+
+pub mod contents {
+    pub const ORBS: usize = 0;
+    pub const DIAMONDS: usize = 1;
+    pub const FIRST_ITEM: usize = 2;
+    pub const SECOND_ITEM: usize = 3;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ChestReward {
+    pub orbs: Orbs,
+    pub diamonds: Diamonds,
+    pub items: Vec<Item>,
+    pub left: Duration,
+    pub opened: u32,
+}
+
+impl ChestReward {
+    pub fn is_ready(&self) -> bool {
+        self.left.get().is_zero()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ChestRewards {
+    pub small: ChestReward,
+    pub large: ChestReward,
+}
+
+impl ChestRewards {
+    pub const fn get(&self, chest: Chest) -> &ChestReward {
+        match chest {
+            Chest::Small => &self.small,
+            Chest::Large => &self.large,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum RewardsError {
+    #[error("failed to get rewards")]
+    Failed,
+    #[error("rewards hash mismatch")]
+    Mismatch,
+    #[error(transparent)]
+    Decode(#[from] crypto_robtop::Error),
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RewardsForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    udid: &'f str,
+    uuid: UserId,
+    reward_type: u8,
+    chk: String,
+    game_version: u16,
+    binary_version: u16,
+    secret: &'f str,
+}
+
+fn parse_chest(values: &[&str], offset: usize) -> Result<ChestReward, robtop::Error> {
+    let content = values
+        .get(offset + indices::CONTENT)
+        .copied()
+        .unwrap_or_default()
+        .split(CONTENT_DELIMITER)
+        .map(str::trim)
+        .collect::<Vec<_>>();
+
+    let mut items = Vec::new();
+
+    for index in [contents::FIRST_ITEM, contents::SECOND_ITEM] {
+        let value: u8 = parse_at(&content, index, keys::ITEM)?;
+
+        if value == NO_ITEM {
+            continue;
+        }
+
+        let item = Item::from_value(value)
+            .ok_or_else(|| InvalidError::new(keys::ITEM, value.to_string()))?;
+
+        items.push(item);
+    }
+
+    let left: u64 = parse_at(values, offset + indices::LEFT, keys::LEFT)?;
+
+    let reward = ChestReward {
+        orbs: Orbs::new(parse_at(&content, contents::ORBS, keys::ORBS)?),
+        diamonds: Diamonds::new(parse_at(&content, contents::DIAMONDS, keys::DIAMONDS)?),
+        items,
+        left: seconds(left),
+        opened: parse_at(values, offset + indices::OPENED, keys::OPENED)?,
+    };
+
+    Ok(reward)
+}
+
+pub(crate) fn parse_rewards(string: &str) -> Result<ChestRewards, robtop::Error> {
+    let values: Vec<&str> = string.split(DELIMITER).collect();
+
+    let rewards = ChestRewards {
+        small: parse_chest(&values, offsets::SMALL)?,
+        large: parse_chest(&values, offsets::LARGE)?,
+    };
+
+    Ok(rewards)
+}
+
+impl Authenticated<'_> {
+    pub async fn get_rewards(&self) -> Result<ChestRewards, RewardsError> {
+        self.send_rewards(NONE).await
+    }
+
+    pub async fn open_chest(&self, chest: Chest) -> Result<ChestRewards, RewardsError> {
+        self.send_rewards(chest.value()).await
+    }
+
+    async fn send_rewards(&self, reward_type: u8) -> Result<ChestRewards, RewardsError> {
+        let auth = &self.state.auth;
+
+        let udid = random::udid();
+
+        let form = RewardsForm {
+            auth: auth.form(),
+            udid: &udid,
+            uuid: auth.reference.id,
+            reward_type,
+            chk: generate_chk(crypto_keys::REWARDS),
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::GET_REWARDS, &form)
+            .await?;
+
+        let response = response.trim();
+
+        if response == FAILED {
+            return Err(RewardsError::Failed);
+        }
+
+        let (encoded, hash) = split_hashed(response).ok_or(RewardsError::Failed)?;
+
+        if !check::verify_with_salt(encoded, salts::REWARDS, hash) {
+            return Err(RewardsError::Mismatch);
+        }
+
+        let decoded = crypto_robtop::decode_string(encoded, crypto_keys::REWARDS)?;
+
+        let rewards = parse_rewards(&decoded)?;
+
+        Ok(rewards)
+    }
+}
@@ -0,0 +1,4 @@
+pub const COMMON: &str = "Wmfd2893gb7";
+pub const ACCOUNT: &str = "Wmfv3899gc9";
+pub const DELETE: &str = "Wmfv2898gc9";
+pub const MODERATOR: &str = "Wmfp3879gc3";
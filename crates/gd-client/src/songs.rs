@@ -0,0 +1,170 @@
+use std::borrow::Cow;
+
+use gd_core::types::id::{ArtistId, SongId};
+use gd_entities::{
+    artists::Artist,
+    songs::{Song, SongReference},
+};
+use gd_internals::{
+    http::RequestError,
+    robtop::{self, InvalidError, Map},
+};
+use percent_encoding::percent_decode_str;
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{client::Client, endpoints, secrets, state::State, utils::owned};
+
+pub const DELIMITER: &str = "~|~";
+
+pub const SONG_DELIMITER: &str = "~:~";
+
+pub const NOT_FOUND: &str = "-1";
+
+pub const DISABLED: &str = "-2";
+
+pub const LIBRARY_OFFSET: u64 = 10_000_000;
+
+pub(crate) mod keys {
+    pub const ID: &str = "1";
+    pub const NAME: &str = "2";
+    pub const ARTIST_ID: &str = "3";
+    pub const ARTIST_NAME: &str = "4";
+    pub const SIZE: &str = "5";
+    pub const ARTIST_VERIFIED: &str = "8";
+    pub const URL: &str = "10";
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SongInfo<'s> {
+    Official(SongReference),
+    Newgrounds(Song<'s>),
+    Library(Song<'s>),
+}
+
+impl<'s> SongInfo<'s> {
+    pub const fn reference(&self) -> SongReference {
+        match self {
+            Self::Official(reference) => *reference,
+            Self::Newgrounds(song) | Self::Library(song) => song.reference(),
+        }
+    }
+
+    pub const fn song(&self) -> Option<&Song<'s>> {
+        match self {
+            Self::Official(_) => None,
+            Self::Newgrounds(song) | Self::Library(song) => Some(song),
+        }
+    }
+
+    pub const fn is_official(&self) -> bool {
+        matches!(self, Self::Official(_))
+    }
+
+    pub const fn is_newgrounds(&self) -> bool {
+        matches!(self, Self::Newgrounds(_))
+    }
+
+    pub const fn is_library(&self) -> bool {
+        matches!(self, Self::Library(_))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetSongError {
+    #[error("song not found")]
+    NotFound,
+    #[error("song is disabled")]
+    Disabled,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Serialize)]
+struct GetSongForm<'f> {
+    #[serde(rename = "songID")]
+    song_id: SongId,
+    secret: &'f str,
+}
+
+pub const fn is_library(id: SongId) -> bool {
+    id.get() >= LIBRARY_OFFSET
+}
+
+pub(crate) fn parse_url(map: &Map<'_>) -> Result<Option<Cow<'static, str>>, robtop::Error> {
+    let Some(url) = map.get(keys::URL) else {
+        return Ok(None);
+    };
+
+    let decoded = percent_decode_str(url)
+        .decode_utf8()
+        .map_err(|_| InvalidError::new(keys::URL, url))?;
+
+    Ok(Some(owned(&decoded)))
+}
+
+pub(crate) fn parse_song(string: &str) -> Result<Song<'static>, robtop::Error> {
+    let map = Map::parse(string, DELIMITER);
+
+    let artist = Artist::builder()
+        .id(ArtistId::new(map.parse_or_default(keys::ARTIST_ID)?))
+        .name(owned(map.get(keys::ARTIST_NAME).unwrap_or_default()))
+        .verified(map.parse_bool(keys::ARTIST_VERIFIED))
+        .build();
+
+    let song = Song::builder()
+        .id(SongId::new(map.parse_required(keys::ID)?))
+        .name(owned(map.get(keys::NAME).unwrap_or_default()))
+        .artist(artist)
+        .size(map.parse_or_default(keys::SIZE)?)
+        .maybe_url(parse_url(&map)?)
+        .build();
+
+    Ok(song)
+}
+
+pub(crate) fn parse_songs(string: &str) -> Result<Vec<Song<'static>>, robtop::Error> {
+    string
+        .split(SONG_DELIMITER)
+        .filter(|item| !item.is_empty())
+        .map(parse_song)
+        .collect()
+}
+
+impl<S: State> Client<S> {
+    pub async fn get_song(
+        &self,
+        reference: SongReference,
+    ) -> Result<SongInfo<'static>, GetSongError> {
+        if !reference.is_custom() {
+            return Ok(SongInfo::Official(reference));
+        }
+
+        let form = GetSongForm {
+            song_id: reference.id,
+            secret: secrets::COMMON,
+        };
+
+        let response = self.session.http.post(endpoints::GET_SONG, &form).await?;
+
+        let response = response.trim();
+
+        match response {
+            NOT_FOUND => return Err(GetSongError::NotFound),
+            DISABLED => return Err(GetSongError::Disabled),
+            _ => {}
+        }
+
+        let song = parse_song(response)?;
+
+        let info = if is_library(song.id) {
+            SongInfo::Library(song)
+        } else {
+            SongInfo::Newgrounds(song)
+        };
+
+        Ok(info)
+    }
+}
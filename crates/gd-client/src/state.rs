@@ -6,7 +6,9 @@ mod sealed {
     pub trait Sealed {}
 }
 
-pub trait State: sealed::Sealed {}
+pub trait State: sealed::Sealed {
+    fn auth(&self) -> Option<&Auth<'_>>;
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, IntoOwned)]
 pub struct Simple;
@@ -31,5 +33,14 @@ impl<'a> Authenticated<'a> {
 impl sealed::Sealed for Simple {}
 impl sealed::Sealed for Authenticated<'_> {}
 
-impl State for Simple {}
-impl State for Authenticated<'_> {}
+impl State for Simple {
+    fn auth(&self) -> Option<&Auth<'_>> {
+        None
+    }
+}
+
+impl State for Authenticated<'_> {
+    fn auth(&self) -> Option<&Auth<'_>> {
+        Some(&self.auth)
+    }
+}
@@ -0,0 +1,153 @@
+use gd_core::types::{id::TimelyId, time::Duration};
+use gd_entities::levels::Level;
+use gd_enums::timely::Type;
+use gd_internals::{
+    http::RequestError,
+    robtop::{self, InvalidError},
+};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{
+    client::Client,
+    endpoints,
+    levels::{DownloadId, DownloadLevelError},
+    secrets,
+    state::State,
+    utils::seconds,
+    versions,
+};
+
+pub const DELIMITER: char = '|';
+
+pub const NOT_FOUND: &str = "-1";
+
+pub mod offsets {
+    pub const WEEKLY: u32 = 100_000;
+    pub const EVENT: u32 = 200_000;
+}
+
+pub mod queries {
+    pub const DAILY: u8 = 0;
+    pub const WEEKLY: u8 = 1;
+    pub const EVENT: u8 = 2;
+}
+
+pub(crate) mod keys {
+    pub const ID: &str = "id";
+    pub const LEFT: &str = "left";
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Timely {
+    pub timely_type: Type,
+    pub id: TimelyId,
+    pub left: Duration,
+}
+
+#[derive(Debug, Error)]
+pub enum TimelyError {
+    #[error("level is not timely")]
+    NotTimely,
+    #[error("timely level not found")]
+    NotFound,
+    #[error(transparent)]
+    Download(#[from] DownloadLevelError),
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TimelyForm<'f> {
+    #[serde(rename = "type")]
+    query: u8,
+    game_version: u16,
+    binary_version: u16,
+    secret: &'f str,
+}
+
+pub const fn query(timely_type: Type) -> Option<u8> {
+    match timely_type {
+        Type::NotTimely => None,
+        Type::Daily => Some(queries::DAILY),
+        Type::Weekly => Some(queries::WEEKLY),
+        Type::Event => Some(queries::EVENT),
+    }
+}
+
+pub const fn split(value: u32) -> (Type, u32) {
+    match value {
+        0 => (Type::NotTimely, value),
+        _ if value >= offsets::EVENT => (Type::Event, value - offsets::EVENT),
+        _ if value >= offsets::WEEKLY => (Type::Weekly, value - offsets::WEEKLY),
+        _ => (Type::Daily, value),
+    }
+}
+
+pub(crate) fn parse_id(key: &str, value: u32) -> Result<(Type, TimelyId), robtop::Error> {
+    let (timely_type, id) = split(value);
+
+    let id = id
+        .try_into()
+        .map_err(|_| InvalidError::new(key, value.to_string()))?;
+
+    Ok((timely_type, TimelyId::new(id)))
+}
+
+pub(crate) fn parse_timely(response: &str, timely_type: Type) -> Result<Timely, robtop::Error> {
+    let (id, left) = response
+        .split_once(DELIMITER)
+        .ok_or_else(|| InvalidError::new(keys::ID, response))?;
+
+    let value = id.parse().map_err(|_| InvalidError::new(keys::ID, id))?;
+
+    let left = left
+        .parse()
+        .map_err(|_| InvalidError::new(keys::LEFT, left))?;
+
+    let (_, id) = parse_id(keys::ID, value)?;
+
+    Ok(Timely {
+        timely_type,
+        id,
+        left: seconds(left),
+    })
+}
+
+impl<S: State> Client<S> {
+    pub async fn get_timely(&self, timely_type: Type) -> Result<Timely, TimelyError> {
+        let query = query(timely_type).ok_or(TimelyError::NotTimely)?;
+
+        let form = TimelyForm {
+            query,
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            secret: secrets::COMMON,
+        };
+
+        let response = self.session.http.post(endpoints::GET_TIMELY, &form).await?;
+
+        let response = response.trim();
+
+        if response == NOT_FOUND {
+            return Err(TimelyError::NotFound);
+        }
+
+        let timely = parse_timely(response, timely_type)?;
+
+        Ok(timely)
+    }
+
+    pub async fn get_timely_level(&self, timely_type: Type) -> Result<Level<'static>, TimelyError> {
+        if !timely_type.is_timely() {
+            return Err(TimelyError::NotTimely);
+        }
+
+        let level = self.download_level(DownloadId::Timely(timely_type)).await?;
+
+        Ok(level)
+    }
+}
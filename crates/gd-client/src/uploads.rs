@@ -0,0 +1,275 @@
+use bon::Builder;
+use gd_core::types::{
+    id::{LevelId, SongId},
+    password::Password,
+    reward::{RewardCoins, RewardStars},
+    statistics::ObjectCount,
+    str::Str,
+    time::Duration,
+    version::Version,
+};
+use gd_crypto::{base64, check, keys as crypto_keys, random, salts};
+use gd_entities::{
+    levels::{Data, EncodeError, Level},
+    songs::SongReference,
+};
+use gd_enums::levels::{Length, Visibility};
+use gd_internals::http::RequestError;
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{auth::AuthForm, client::Authenticated, endpoints, secrets, utils::flag, versions};
+
+pub const FIRST_VERSION: u16 = 1;
+
+pub const COPY_OFFSET: u32 = 1_000_000;
+
+pub const SUCCESS: &str = "1";
+
+pub const FAILED: &str = "-1";
+pub const TOO_FAST: &str = "-2";
+pub const BANNED: &str = "-3";
+
+pub mod passwords {
+    pub const NO_COPY: u32 = 0;
+    pub const FREE_COPY: u32 = 1;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Builder)]
+pub struct LevelUpload<'u> {
+    #[builder(default)]
+    pub id: LevelId,
+    #[builder(into)]
+    pub name: Str<'u>,
+    #[builder(default, into)]
+    pub description: Str<'u>,
+    pub data: Data<'u>,
+    #[builder(default)]
+    pub song: SongReference,
+    #[builder(default = Version::new(FIRST_VERSION))]
+    pub version: Version,
+    #[builder(default)]
+    pub password: Password,
+    #[builder(default)]
+    pub visibility: Visibility,
+    #[builder(default)]
+    pub requested_stars: RewardStars,
+    #[builder(default)]
+    pub length: Length,
+    #[builder(default)]
+    pub two_player: bool,
+    #[builder(default)]
+    pub coins: RewardCoins,
+    #[builder(default)]
+    pub low_detail: bool,
+    #[builder(default)]
+    pub object_count: ObjectCount,
+    #[builder(default)]
+    pub editor_time: Duration,
+    #[builder(default)]
+    pub copies_time: Duration,
+    #[builder(default)]
+    pub original_id: LevelId,
+}
+
+impl LevelUpload<'_> {
+    pub fn update(mut self, level: &Level<'_>) -> Self {
+        self.id = level.reference.id;
+        self.version = Version::new(level.version.get().saturating_add(1));
+
+        self
+    }
+
+    pub const fn is_update(&self) -> bool {
+        self.id.get() != 0
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum UploadLevelError {
+    #[error(transparent)]
+    Encode(#[from] EncodeError),
+    #[error("banned from uploading levels")]
+    Banned,
+    #[error("uploading levels too fast")]
+    TooFast,
+    #[error("failed to upload level")]
+    Failed,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+}
+
+#[derive(Debug, Error)]
+pub enum ManageLevelError {
+    #[error("failed to manage level")]
+    Failed,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateDescriptionForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    #[serde(rename = "levelID")]
+    level_id: LevelId,
+    level_desc: String,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DeleteLevelForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    #[serde(rename = "levelID")]
+    level_id: LevelId,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UploadLevelForm<'f> {
+    #[serde(flatten)]
+    auth: AuthForm,
+    user_name: &'f str,
+    #[serde(rename = "levelID")]
+    level_id: LevelId,
+    level_name: &'f str,
+    level_desc: String,
+    level_version: Version,
+    level_length: u8,
+    audio_track: SongId,
+    #[serde(rename = "songID")]
+    song_id: SongId,
+    password: u32,
+    original: LevelId,
+    two_player: Option<u8>,
+    objects: ObjectCount,
+    coins: RewardCoins,
+    requested_stars: RewardStars,
+    unlisted: u8,
+    ldm: Option<u8>,
+    wt: u64,
+    wt2: u64,
+    level_string: &'f str,
+    seed: String,
+    seed2: String,
+    game_version: u16,
+    binary_version: u16,
+    secret: &'f str,
+}
+
+pub const fn password_value(password: Password) -> u32 {
+    match password {
+        Password::NoCopy => passwords::NO_COPY,
+        Password::FreeCopy => passwords::FREE_COPY,
+        Password::CodeCopy(code) => COPY_OFFSET + code.get(),
+    }
+}
+
+impl Authenticated<'_> {
+    pub async fn upload_level(&self, upload: LevelUpload<'_>) -> Result<LevelId, UploadLevelError> {
+        let auth = &self.state.auth;
+
+        let data = upload.data.into_unprocessed()?;
+
+        let (audio_track, song_id) = if upload.song.is_custom() {
+            (SongId::default(), upload.song.id)
+        } else {
+            (upload.song.id, SongId::default())
+        };
+
+        let form = UploadLevelForm {
+            auth: auth.form(),
+            user_name: auth.credentials.name.as_ref(),
+            level_id: upload.id,
+            level_name: upload.name.as_ref(),
+            level_desc: base64::encode(upload.description.as_ref()),
+            level_version: upload.version,
+            level_length: upload.length.value(),
+            audio_track,
+            song_id,
+            password: password_value(upload.password),
+            original: upload.original_id,
+            two_player: flag(upload.two_player),
+            objects: upload.object_count,
+            coins: upload.coins,
+            requested_stars: upload.requested_stars,
+            unlisted: upload.visibility.value(),
+            ldm: flag(upload.low_detail),
+            wt: upload.editor_time.get().as_secs(),
+            wt2: upload.copies_time.get().as_secs(),
+            level_string: data.as_ref(),
+            seed: random::seed_string(),
+            seed2: check::of_sampled_with_salt(data.as_ref(), salts::LEVEL, crypto_keys::LEVEL),
+            game_version: versions::GAME,
+            binary_version: versions::BINARY,
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::UPLOAD_LEVEL, &form)
+            .await?;
+
+        let response = response.trim();
+
+        match response {
+            FAILED => Err(UploadLevelError::Failed),
+            TOO_FAST => Err(UploadLevelError::TooFast),
+            BANNED => Err(UploadLevelError::Banned),
+            _ => response
+                .parse()
+                .map(LevelId::new)
+                .map_err(|_| UploadLevelError::Failed),
+        }
+    }
+
+    pub async fn update_level_description(
+        &self,
+        level_id: LevelId,
+        description: &str,
+    ) -> Result<(), ManageLevelError> {
+        let form = UpdateDescriptionForm {
+            auth: self.state.auth.form(),
+            level_id,
+            level_desc: base64::encode(description),
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::UPDATE_LEVEL_DESCRIPTION, &form)
+            .await?;
+
+        if response.trim() != SUCCESS {
+            return Err(ManageLevelError::Failed);
+        }
+
+        Ok(())
+    }
+
+    pub async fn delete_level(&self, level_id: LevelId) -> Result<(), ManageLevelError> {
+        let form = DeleteLevelForm {
+            auth: self.state.auth.form(),
+            level_id,
+            secret: secrets::DELETE,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::DELETE_LEVEL, &form)
+            .await?;
+
+        if response.trim() != SUCCESS {
+            return Err(ManageLevelError::Failed);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,378 @@
+use gd_core::types::{
+    id::{AccountId, ColorId, IconId, LongIconId, RoleId, UserId},
+    info::{DemonInfo, DemonInfoGroup, DemonInfoSpecial, LevelInfo, PlatformerInfo},
+    statistics::{
+        CreatorPoints, Demons, Diamonds, Levels, Moons, Rank, SecretCoins, Stars, UserCoins,
+    },
+};
+use gd_entities::users::{
+    User, UserCosmetics, UserNotifications, UserReference, UserSocials, UserStates, UserStatistics,
+};
+use gd_enums::{comments, friend_requests, friends, icons, messages};
+use gd_internals::{
+    http::RequestError,
+    robtop::{self, Map},
+};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{
+    auth::AuthForm,
+    client::Client,
+    endpoints,
+    pages::{self, Page},
+    secrets,
+    state::State,
+    utils::{nth, owned, parse_value},
+};
+
+pub const DELIMITER: &str = ":";
+
+pub const INFO_DELIMITER: &str = ",";
+
+pub const NOT_FOUND: &str = "-1";
+
+pub const NO_RESULTS: &str = "-1";
+
+pub(crate) mod keys {
+    pub const NAME: &str = "1";
+    pub const ID: &str = "2";
+    pub const STARS: &str = "3";
+    pub const DEMONS: &str = "4";
+    pub const CREATOR_POINTS: &str = "8";
+    pub const ICON_ID: &str = "9";
+    pub const COLOR_1_ID: &str = "10";
+    pub const COLOR_2_ID: &str = "11";
+    pub const SECRET_COINS: &str = "13";
+    pub const ICON_TYPE: &str = "14";
+    pub const GLOW_LEGACY: &str = "15";
+    pub const ACCOUNT_ID: &str = "16";
+    pub const USER_COINS: &str = "17";
+    pub const MESSAGE_STATE: &str = "18";
+    pub const FRIEND_REQUEST_STATE: &str = "19";
+    pub const YOUTUBE: &str = "20";
+    pub const CUBE_ID: &str = "21";
+    pub const SHIP_ID: &str = "22";
+    pub const BALL_ID: &str = "23";
+    pub const UFO_ID: &str = "24";
+    pub const WAVE_ID: &str = "25";
+    pub const ROBOT_ID: &str = "26";
+    pub const STREAK_ID: &str = "27";
+    pub const GLOW: &str = "28";
+    pub const RANK: &str = "30";
+    pub const FRIEND_STATE: &str = "31";
+    pub const NEW_MESSAGES: &str = "38";
+    pub const NEW_FRIEND_REQUESTS: &str = "39";
+    pub const NEW_FRIENDS: &str = "40";
+    pub const SPIDER_ID: &str = "43";
+    pub const X: &str = "44";
+    pub const TWITCH: &str = "45";
+    pub const DIAMONDS: &str = "46";
+    pub const EXPLOSION_ID: &str = "48";
+    pub const ROLE_ID: &str = "49";
+    pub const COMMENT_STATE: &str = "50";
+    pub const COLOR_3_ID: &str = "51";
+    pub const MOONS: &str = "52";
+    pub const SWING_ID: &str = "53";
+    pub const JETPACK_ID: &str = "54";
+    pub const DEMON_INFO: &str = "55";
+    pub const LEVEL_INFO: &str = "56";
+    pub const PLATFORMER_INFO: &str = "57";
+}
+
+#[derive(Debug, Error)]
+pub enum GetUserError {
+    #[error("user not found")]
+    NotFound,
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SearchUsersError {
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error(transparent)]
+    Parse(#[from] robtop::Error),
+}
+
+#[derive(Debug, Serialize)]
+struct SearchUsersForm<'f> {
+    #[serde(rename = "str")]
+    query: &'f str,
+    page: u32,
+    total: u32,
+    secret: &'f str,
+}
+
+#[derive(Debug, Serialize)]
+struct GetUserForm<'f> {
+    #[serde(rename = "targetAccountID")]
+    target_account_id: AccountId,
+    #[serde(flatten)]
+    auth: Option<AuthForm>,
+    secret: &'f str,
+}
+
+pub(crate) fn parse_reference(map: &Map<'_>) -> Result<UserReference<'static>, robtop::Error> {
+    let reference = UserReference::builder()
+        .id(UserId::new(map.parse_or_default(keys::ID)?))
+        .name(owned(map.get(keys::NAME).unwrap_or_default()))
+        .account_id(AccountId::new(map.parse_or_default(keys::ACCOUNT_ID)?))
+        .build();
+
+    Ok(reference)
+}
+
+pub(crate) fn parse_demon_info(map: &Map<'_>) -> Result<DemonInfo, robtop::Error> {
+    let values: Vec<u16> = map.parse_list(keys::DEMON_INFO, INFO_DELIMITER)?;
+
+    let group = |offset: usize| {
+        DemonInfoGroup::builder()
+            .easy(Demons::new(nth(&values, offset)))
+            .medium(Demons::new(nth(&values, offset + 1)))
+            .hard(Demons::new(nth(&values, offset + 2)))
+            .insane(Demons::new(nth(&values, offset + 3)))
+            .extreme(Demons::new(nth(&values, offset + 4)))
+            .build()
+    };
+
+    let special = DemonInfoSpecial::builder()
+        .weekly(Demons::new(nth(&values, 10)))
+        .gauntlet(Demons::new(nth(&values, 11)))
+        .build();
+
+    let demon_info = DemonInfo::builder()
+        .regular(group(0))
+        .platformer(group(5))
+        .special(special)
+        .build();
+
+    Ok(demon_info)
+}
+
+pub(crate) fn parse_level_info(map: &Map<'_>) -> Result<LevelInfo, robtop::Error> {
+    let values: Vec<u32> = map.parse_list(keys::LEVEL_INFO, INFO_DELIMITER)?;
+
+    let level_info = LevelInfo::builder()
+        .auto(Levels::new(nth(&values, 0)))
+        .easy(Levels::new(nth(&values, 1)))
+        .normal(Levels::new(nth(&values, 2)))
+        .hard(Levels::new(nth(&values, 3)))
+        .harder(Levels::new(nth(&values, 4)))
+        .insane(Levels::new(nth(&values, 5)))
+        .daily(Levels::new(nth(&values, 6)))
+        .gauntlet(Levels::new(nth(&values, 7)))
+        .build();
+
+    Ok(level_info)
+}
+
+pub(crate) fn parse_platformer_info(map: &Map<'_>) -> Result<PlatformerInfo, robtop::Error> {
+    let values: Vec<u32> = map.parse_list(keys::PLATFORMER_INFO, INFO_DELIMITER)?;
+
+    let platformer_info = PlatformerInfo::builder()
+        .auto(Levels::new(nth(&values, 0)))
+        .easy(Levels::new(nth(&values, 1)))
+        .normal(Levels::new(nth(&values, 2)))
+        .hard(Levels::new(nth(&values, 3)))
+        .harder(Levels::new(nth(&values, 4)))
+        .insane(Levels::new(nth(&values, 5)))
+        .build();
+
+    Ok(platformer_info)
+}
+
+pub(crate) fn parse_statistics(map: &Map<'_>) -> Result<UserStatistics, robtop::Error> {
+    let statistics = UserStatistics::builder()
+        .stars(Stars::new(map.parse_or_default(keys::STARS)?))
+        .moons(Moons::new(map.parse_or_default(keys::MOONS)?))
+        .demons(Demons::new(map.parse_or_default(keys::DEMONS)?))
+        .diamonds(Diamonds::new(map.parse_or_default(keys::DIAMONDS)?))
+        .user_coins(UserCoins::new(map.parse_or_default(keys::USER_COINS)?))
+        .secret_coins(SecretCoins::new(map.parse_or_default(keys::SECRET_COINS)?))
+        .creator_points(CreatorPoints::new(
+            map.parse_or_default(keys::CREATOR_POINTS)?,
+        ))
+        .rank(Rank::new(map.parse_or_default(keys::RANK)?))
+        .demon_info(parse_demon_info(map)?)
+        .level_info(parse_level_info(map)?)
+        .platformer_info(parse_platformer_info(map)?)
+        .build();
+
+    Ok(statistics)
+}
+
+pub(crate) fn parse_cosmetics(map: &Map<'_>) -> Result<UserCosmetics, robtop::Error> {
+    let icon_type: icons::Type = parse_value(map, keys::ICON_TYPE)?;
+
+    let cube_id = LongIconId::new(map.parse_or_default(keys::CUBE_ID)?);
+
+    // leaderboards include the displayed icon, user info does not, so we fall back to the cube
+    let icon_id = map
+        .parse_optional(keys::ICON_ID)?
+        .map_or(cube_id, LongIconId::new);
+
+    let cosmetics = UserCosmetics::builder()
+        .color_1_id(ColorId::new(map.parse_or_default(keys::COLOR_1_ID)?))
+        .color_2_id(ColorId::new(map.parse_or_default(keys::COLOR_2_ID)?))
+        .color_3_id(ColorId::new(map.parse_or_default(keys::COLOR_3_ID)?))
+        .glow(map.parse_bool(keys::GLOW) || map.parse_bool(keys::GLOW_LEGACY))
+        .icon_type(icon_type)
+        .icon_id(icon_id)
+        .cube_id(cube_id)
+        .ship_id(IconId::new(map.parse_or_default(keys::SHIP_ID)?))
+        .ball_id(IconId::new(map.parse_or_default(keys::BALL_ID)?))
+        .ufo_id(IconId::new(map.parse_or_default(keys::UFO_ID)?))
+        .wave_id(IconId::new(map.parse_or_default(keys::WAVE_ID)?))
+        .robot_id(IconId::new(map.parse_or_default(keys::ROBOT_ID)?))
+        .spider_id(IconId::new(map.parse_or_default(keys::SPIDER_ID)?))
+        .swing_id(IconId::new(map.parse_or_default(keys::SWING_ID)?))
+        .jetpack_id(IconId::new(map.parse_or_default(keys::JETPACK_ID)?))
+        .explosion_id(IconId::new(map.parse_or_default(keys::EXPLOSION_ID)?))
+        .streak_id(IconId::new(map.parse_or_default(keys::STREAK_ID)?))
+        .build();
+
+    Ok(cosmetics)
+}
+
+pub(crate) fn parse_states(map: &Map<'_>) -> Result<UserStates, robtop::Error> {
+    let messages: messages::State = parse_value(map, keys::MESSAGE_STATE)?;
+    let friend_requests: friend_requests::State = parse_value(map, keys::FRIEND_REQUEST_STATE)?;
+    let comments: comments::State = parse_value(map, keys::COMMENT_STATE)?;
+    let friend: friends::State = parse_value(map, keys::FRIEND_STATE)?;
+
+    let states = UserStates::builder()
+        .messages(messages)
+        .friend_requests(friend_requests)
+        .comments(comments)
+        .friend(friend)
+        .build();
+
+    Ok(states)
+}
+
+pub(crate) fn parse_socials(map: &Map<'_>) -> UserSocials<'static> {
+    UserSocials::builder()
+        .maybe_youtube(map.get(keys::YOUTUBE).map(owned))
+        .maybe_x(map.get(keys::X).map(owned))
+        .maybe_twitch(map.get(keys::TWITCH).map(owned))
+        .build()
+}
+
+pub(crate) fn parse_notifications(
+    map: &Map<'_>,
+) -> Result<Option<UserNotifications>, robtop::Error> {
+    let messages = map.parse_optional(keys::NEW_MESSAGES)?;
+    let friend_requests = map.parse_optional(keys::NEW_FRIEND_REQUESTS)?;
+    let friends = map.parse_optional(keys::NEW_FRIENDS)?;
+
+    // these are only sent when the caller is viewing their own profile
+    if messages.is_none() && friend_requests.is_none() && friends.is_none() {
+        return Ok(None);
+    }
+
+    let notifications = UserNotifications::builder()
+        .messages(messages.unwrap_or_default())
+        .friend_requests(friend_requests.unwrap_or_default())
+        .friends(friends.unwrap_or_default())
+        .build();
+
+    Ok(Some(notifications))
+}
+
+pub(crate) fn parse_user(string: &str) -> Result<User<'static>, robtop::Error> {
+    let map = Map::parse(string, DELIMITER);
+
+    // user info does not report bans, so `banned` is left unknown
+    let user = User::builder()
+        .reference(parse_reference(&map)?)
+        .role_id(RoleId::new(map.parse_or_default(keys::ROLE_ID)?))
+        .statistics(parse_statistics(&map)?)
+        .cosmetics(parse_cosmetics(&map)?)
+        .states(parse_states(&map)?)
+        .socials(parse_socials(&map))
+        .maybe_notifications(parse_notifications(&map)?)
+        .build();
+
+    Ok(user)
+}
+
+pub(crate) fn parse_partial_user(string: &str) -> Result<User<'static>, robtop::Error> {
+    let map = Map::parse(string, DELIMITER);
+
+    let user = User::builder()
+        .reference(parse_reference(&map)?)
+        .statistics(parse_statistics(&map)?)
+        .cosmetics(parse_cosmetics(&map)?)
+        .build();
+
+    Ok(user)
+}
+
+pub(crate) fn parse_users(string: &str) -> Result<Vec<User<'static>>, robtop::Error> {
+    string
+        .split(pages::ITEM_DELIMITER)
+        .filter(|item| !item.is_empty())
+        .map(parse_partial_user)
+        .collect()
+}
+
+impl<S: State> Client<S> {
+    pub async fn get_user(&self, account_id: AccountId) -> Result<User<'static>, GetUserError> {
+        let form = GetUserForm {
+            target_account_id: account_id,
+            auth: self.state.auth().map(|auth| auth.form()),
+            secret: secrets::COMMON,
+        };
+
+        let response = self.session.http.post(endpoints::GET_USER, &form).await?;
+
+        let response = response.trim();
+
+        if response == NOT_FOUND {
+            return Err(GetUserError::NotFound);
+        }
+
+        let user = parse_user(response)?;
+
+        Ok(user)
+    }
+}
+
+impl<S: State> Client<S> {
+    pub async fn search_users(
+        &self,
+        query: &str,
+        page: u32,
+    ) -> Result<Page<User<'static>>, SearchUsersError> {
+        let form = SearchUsersForm {
+            query,
+            page,
+            total: 0,
+            secret: secrets::COMMON,
+        };
+
+        let response = self
+            .session
+            .http
+            .post(endpoints::SEARCH_USERS, &form)
+            .await?;
+
+        let response = response.trim();
+
+        if response == NO_RESULTS {
+            return Ok(Page::empty());
+        }
+
+        let (users, trailer) = response
+            .split_once(pages::SECTION_DELIMITER)
+            .unwrap_or((response, ""));
+
+        let page = pages::parse_page(parse_users(users)?, trailer)?;
+
+        Ok(page)
+    }
+}
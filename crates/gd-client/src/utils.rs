@@ -0,0 +1,128 @@
+use std::{borrow::Cow, str::FromStr, time::Duration as StandardDuration};
+
+use gd_core::types::{
+    color::Color,
+    id::LevelId,
+    time::{Duration, Instant},
+};
+use gd_crypto::{base64, random, robtop as crypto_robtop};
+use gd_internals::robtop::{self, InvalidError, Map};
+
+pub(crate) fn owned(string: &str) -> Cow<'static, str> {
+    Cow::Owned(string.to_owned())
+}
+
+pub(crate) fn nth<T: Copy + Default>(values: &[T], index: usize) -> T {
+    values.get(index).copied().unwrap_or_default()
+}
+
+pub(crate) fn flag(value: bool) -> Option<u8> {
+    value.then_some(1)
+}
+
+pub(crate) fn parse_value<T: TryFrom<u8>>(map: &Map<'_>, key: &str) -> Result<T, robtop::Error> {
+    let value: u8 = map.parse_or_default(key)?;
+
+    T::try_from(value).map_err(|_| InvalidError::new(key, value.to_string()).into())
+}
+
+pub(crate) fn parse_base64(map: &Map<'_>, key: &str) -> Result<Cow<'static, str>, robtop::Error> {
+    let Some(value) = map.get(key) else {
+        return Ok(Cow::Borrowed(""));
+    };
+
+    let decoded = base64::decode_string(value).map_err(|_| InvalidError::new(key, value))?;
+
+    Ok(Cow::Owned(decoded))
+}
+
+pub(crate) const LIST_DELIMITER: &str = ",";
+
+pub(crate) fn parse_level_ids(map: &Map<'_>, key: &str) -> Result<Vec<LevelId>, robtop::Error> {
+    let values: Vec<u64> = map.parse_list(key, LIST_DELIMITER)?;
+
+    Ok(values.into_iter().map(LevelId::new).collect())
+}
+
+pub(crate) const COLOR_DELIMITER: &str = ",";
+
+pub(crate) fn parse_color(map: &Map<'_>, key: &str) -> Result<Color, robtop::Error> {
+    let values: Vec<u8> = map.parse_list(key, COLOR_DELIMITER)?;
+
+    if values.is_empty() {
+        return Ok(Color::WHITE);
+    }
+
+    Ok(Color::from_rgb(
+        nth(&values, 0),
+        nth(&values, 1),
+        nth(&values, 2),
+    ))
+}
+
+pub(crate) const SECOND: u64 = 1;
+pub(crate) const MINUTE: u64 = 60 * SECOND;
+pub(crate) const HOUR: u64 = 60 * MINUTE;
+pub(crate) const DAY: u64 = 24 * HOUR;
+pub(crate) const WEEK: u64 = 7 * DAY;
+pub(crate) const MONTH: u64 = 30 * DAY;
+pub(crate) const YEAR: u64 = 365 * DAY;
+
+pub(crate) const fn seconds(value: u64) -> Duration {
+    Duration::new(StandardDuration::from_secs(value))
+}
+
+pub(crate) const fn milliseconds(value: u64) -> Duration {
+    Duration::new(StandardDuration::from_millis(value))
+}
+
+pub(crate) fn parse_ago(string: &str) -> Option<Duration> {
+    let (amount, unit) = string.trim().split_once(' ')?;
+
+    let amount: u64 = amount.parse().ok()?;
+
+    let unit = match unit.trim_end_matches('s') {
+        "second" => SECOND,
+        "minute" => MINUTE,
+        "hour" => HOUR,
+        "day" => DAY,
+        "week" => WEEK,
+        "month" => MONTH,
+        "year" => YEAR,
+        _ => return None,
+    };
+
+    Some(seconds(amount.checked_mul(unit)?))
+}
+
+pub(crate) fn parse_instant(map: &Map<'_>, key: &str) -> Option<Instant> {
+    map.get(key).and_then(parse_ago).and_then(Instant::ago)
+}
+
+pub(crate) fn parse_at<T: FromStr>(
+    values: &[&str],
+    index: usize,
+    key: &str,
+) -> Result<T, InvalidError> {
+    let value = values.get(index).copied().unwrap_or_default();
+
+    value.parse().map_err(|_| InvalidError::new(key, value))
+}
+
+pub(crate) const CHK_SALT_LENGTH: usize = 5;
+
+pub(crate) const HASH_DELIMITER: char = '|';
+
+pub(crate) fn generate_chk(key: &str) -> String {
+    let salt = random::string(CHK_SALT_LENGTH);
+
+    let value = crypto_robtop::encode(random::seed().to_string(), key);
+
+    format!("{salt}{value}")
+}
+
+pub(crate) fn split_hashed(response: &str) -> Option<(&str, &str)> {
+    let (encoded, hash) = response.split_once(HASH_DELIMITER)?;
+
+    Some((encoded.get(CHK_SALT_LENGTH..)?, hash))
+}
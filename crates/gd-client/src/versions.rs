@@ -0,0 +1,2 @@
+pub const GAME: u16 = 22;
+pub const BINARY: u16 = 45;
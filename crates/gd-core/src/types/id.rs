@@ -51,6 +51,15 @@ custom_id!(
     // large
     AccountId => u64,
     ArtistId => u64,
+    CommentId => u64,
+    FriendRequestId => u64,
+    GauntletId => u64,
+    LevelId => u64,
+    ListId => u64,
+    MapPackId => u64,
+    MessageId => u64,
+    QuestId => u64,
+    SongId => u64,
     UserId => u64,
     // small
     TimelyId => u16,
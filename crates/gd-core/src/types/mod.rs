@@ -14,7 +14,10 @@ pub mod version;
 pub use color::{AlphaColor, Color, OpaqueColor};
 
 #[doc(inline)]
-pub use id::{AccountId, ArtistId, CustomId, Id, TypedId, UntypedId, UserId};
+pub use id::{
+    AccountId, ArtistId, CommentId, CustomId, FriendRequestId, GauntletId, Id, LevelId, ListId,
+    MapPackId, MessageId, QuestId, SongId, TypedId, UntypedId, UserId,
+};
 
 #[doc(inline)]
 pub use info::{DemonInfo, DemonInfoGroup, DemonInfoSpecial, LevelInfo, PlatformerInfo};
@@ -31,14 +34,14 @@ pub use reward::{Reward, RewardCoins, RewardMoons, RewardStars};
 #[doc(inline)]
 pub use statistics::{
     Attempts, Clicks, CreatorPoints, Demons, Diamonds, Downloads, Jumps, Levels, Moons,
-    ObjectCount, Place, Rank, Rating, Score, SecretCoins, Stars, UserCoins,
+    ObjectCount, Orbs, Place, Points, Rank, Rating, Score, SecretCoins, Stars, UserCoins,
 };
 
 #[doc(inline)]
 pub use str::Str;
 
 #[doc(inline)]
-pub use time::Duration;
+pub use time::{Duration, Instant, TimeSteps};
 
 #[doc(inline)]
 pub use values::{EnumValue, FlagValue};
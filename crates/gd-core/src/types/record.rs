@@ -4,7 +4,7 @@ use ownership::IntoOwned;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::types::time::Duration;
+use crate::types::{statistics::Points, time::Duration};
 
 pub const LIMIT: u8 = 100;
 
@@ -81,4 +81,5 @@ impl Percent {
 pub enum Record {
     Percent(Percent),
     Duration(Duration),
+    Points(Points),
 }
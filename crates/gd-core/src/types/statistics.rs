@@ -18,4 +18,6 @@ new_type!(
     Attempts => u32,
     Jumps => u32,
     Clicks => u32,
+    Points => u32,
+    Orbs => u32,
 );
@@ -21,10 +21,26 @@ impl Instant {
     pub const fn get(self) -> Timestamp {
         self.timestamp
     }
+
+    pub fn now() -> Self {
+        Self::new(Timestamp::now())
+    }
+
+    pub fn from_seconds(seconds: i64) -> Option<Self> {
+        Timestamp::from_second(seconds).ok().map(Self::new)
+    }
+
+    pub fn ago(duration: Duration) -> Option<Self> {
+        let signed = duration.get_signed()?;
+
+        Timestamp::now().checked_sub(signed).ok().map(Self::new)
+    }
 }
 
 impl_identity!(Instant);
 
+crate::new_type!(TimeSteps => u64);
+
 // TODO: custom (de)serialization
 #[derive(
     Debug,
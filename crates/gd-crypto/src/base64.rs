@@ -4,10 +4,19 @@ use base64::{
 };
 use thiserror::Error;
 
+use crate::utf8;
+
 #[derive(Debug, Error)]
 #[error("failed to decode base64 data")]
 pub struct Error(#[from] DecodeError);
 
+#[derive(Debug, Error)]
+#[error("failed to decode base64 into string")]
+pub enum StringError {
+    Base64(#[from] Error),
+    Utf8(#[from] utf8::Error),
+}
+
 pub fn encode<D: AsRef<[u8]>>(data: D) -> String {
     URL_SAFE.encode(data)
 }
@@ -17,3 +26,11 @@ pub fn decode<D: AsRef<[u8]>>(data: D) -> Result<Vec<u8>, Error> {
 
     Ok(output)
 }
+
+pub fn decode_string<D: AsRef<[u8]>>(data: D) -> Result<String, StringError> {
+    let output = decode(data)?;
+
+    let string = utf8::convert(output)?;
+
+    Ok(string)
+}
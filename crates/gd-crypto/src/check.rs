@@ -1,5 +1,7 @@
 use crate::{robtop, sha1};
 
+pub const SAMPLES: usize = 50;
+
 pub fn of<D: AsRef<[u8]>, K: AsRef<[u8]>>(data: D, key: K) -> String {
     fn of_inner(data: &[u8], key: &[u8]) -> String {
         robtop::encode(sha1::hash(data), key)
@@ -19,3 +21,37 @@ pub fn of_with_salt<D: AsRef<[u8]>, S: AsRef<[u8]>, K: AsRef<[u8]>>(
 
     of_with_salt_inner(data.as_ref(), salt.as_ref(), key.as_ref())
 }
+
+pub fn verify_with_salt<D: AsRef<[u8]>, S: AsRef<[u8]>, H: AsRef<str>>(
+    data: D,
+    salt: S,
+    hash: H,
+) -> bool {
+    sha1::hash_with_salt(data, salt) == hash.as_ref()
+}
+
+pub fn sample<D: AsRef<[u8]>>(data: D, count: usize) -> Vec<u8> {
+    fn sample_inner(data: &[u8], count: usize) -> Vec<u8> {
+        let length = data.len();
+
+        if length < count {
+            return data.to_owned();
+        }
+
+        data.iter()
+            .step_by(length / count)
+            .take(count)
+            .copied()
+            .collect()
+    }
+
+    sample_inner(data.as_ref(), count)
+}
+
+pub fn of_sampled_with_salt<D: AsRef<[u8]>, S: AsRef<[u8]>, K: AsRef<[u8]>>(
+    data: D,
+    salt: S,
+    key: K,
+) -> String {
+    of_with_salt(sample(data, SAMPLES), salt, key)
+}
@@ -0,0 +1,10 @@
+pub const LEVEL_PASSWORD: &str = "26364";
+pub const COMMENT: &str = "29481";
+pub const MESSAGE: &str = "14251";
+pub const LEVEL_LEADERBOARD: &str = "39673";
+pub const LIKE: &str = "58281";
+pub const LEVEL: &str = "41274";
+pub const PROFILE: &str = "85271";
+pub const REWARDS: &str = "59182";
+pub const QUESTS: &str = "19847";
+pub const RATE: &str = LIKE;
@@ -2,7 +2,10 @@ pub mod aes;
 pub mod base64;
 pub mod check;
 pub mod key;
+pub mod keys;
+pub mod random;
 pub mod robtop;
+pub mod salts;
 #[macro_use]
 pub mod sha1;
 pub mod utf8;
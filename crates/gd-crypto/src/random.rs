@@ -0,0 +1,33 @@
+use rand::{Rng, distr::Alphanumeric, rng};
+
+pub const UDID_PREFIX: &str = "S";
+
+pub const UDID_MIN: u64 = 100_000_000_000_000;
+pub const UDID_MAX: u64 = 999_999_999_999_999;
+
+pub const SEED_LENGTH: usize = 10;
+
+pub const SEED_MIN: u32 = 1_000;
+pub const SEED_MAX: u32 = 9_999;
+
+pub fn string(length: usize) -> String {
+    rng()
+        .sample_iter(Alphanumeric)
+        .take(length)
+        .map(char::from)
+        .collect()
+}
+
+pub fn udid() -> String {
+    let value = rng().random_range(UDID_MIN..=UDID_MAX);
+
+    format!("{UDID_PREFIX}{value}")
+}
+
+pub fn seed() -> u32 {
+    rng().random_range(SEED_MIN..=SEED_MAX)
+}
+
+pub fn seed_string() -> String {
+    string(SEED_LENGTH)
+}
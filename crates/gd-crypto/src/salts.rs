@@ -0,0 +1,9 @@
+pub const GJP: &str = "mI29fmAnxgTs";
+pub const COMMENT: &str = "xPT6iUrtws0J";
+pub const LEVEL_LEADERBOARD: &str = "yPg6pUrtWn0J";
+pub const LIKE: &str = "ysg6pUrtjn0J";
+pub const LEVEL: &str = "xI25fpAapCQg";
+pub const PROFILE: &str = "xI35fsAapCRg";
+pub const REWARDS: &str = "pC26fpYaQCtg";
+pub const QUESTS: &str = "oC36fpYaPtdg";
+pub const RATE: &str = LIKE;
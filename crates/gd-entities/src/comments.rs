@@ -0,0 +1,102 @@
+use std::fmt;
+
+use bon::Builder;
+use gd_core::types::{
+    color::Color, id::CommentId, record::Record, statistics::Rating, str::Str, time::Instant,
+};
+use gd_enums::comments;
+use ownership::IntoOwned;
+
+use crate::{entities::Entity, levels::LevelReference, users::UserReference};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Builder, IntoOwned)]
+pub struct CoreComment<'c> {
+    pub id: CommentId,
+    pub author: UserReference<'c>,
+    #[builder(default, into)]
+    pub content: Str<'c>,
+    #[builder(default = Color::WHITE)]
+    pub color: Color,
+    #[builder(default)]
+    pub rating: Rating,
+    pub created_at: Option<Instant>,
+}
+
+impl fmt::Display for CoreComment<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.content.fmt(formatter)
+    }
+}
+
+impl Entity for CoreComment<'_> {
+    type Id = CommentId;
+
+    fn id(&self) -> Self::Id {
+        self.id
+    }
+}
+
+pub type UserComment<'c> = CoreComment<'c>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Builder, IntoOwned)]
+pub struct LevelComment<'c> {
+    pub core: CoreComment<'c>,
+    pub level: LevelReference<'c>,
+    pub record: Option<Record>,
+}
+
+impl fmt::Display for LevelComment<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.core.fmt(formatter)
+    }
+}
+
+impl Entity for LevelComment<'_> {
+    type Id = CommentId;
+
+    fn id(&self) -> Self::Id {
+        self.core.id
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, IntoOwned)]
+pub enum Comment<'c> {
+    User(UserComment<'c>),
+    Level(LevelComment<'c>),
+}
+
+impl fmt::Display for Comment<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.core().fmt(formatter)
+    }
+}
+
+impl Entity for Comment<'_> {
+    type Id = CommentId;
+
+    fn id(&self) -> Self::Id {
+        self.core().id
+    }
+}
+
+impl<'c> Comment<'c> {
+    pub const fn core(&self) -> &CoreComment<'c> {
+        match self {
+            Self::User(core) => core,
+            Self::Level(comment) => &comment.core,
+        }
+    }
+
+    pub const fn comment_type(&self) -> comments::Type {
+        match self {
+            Self::User(_) => comments::Type::User,
+            Self::Level(_) => comments::Type::Level,
+        }
+    }
+}
+
+impl<'c> From<LevelComment<'c>> for Comment<'c> {
+    fn from(comment: LevelComment<'c>) -> Self {
+        Self::Level(comment)
+    }
+}
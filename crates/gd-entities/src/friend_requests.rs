@@ -0,0 +1,45 @@
+use std::fmt;
+
+use bon::Builder;
+use gd_core::types::{id::FriendRequestId, str::Str, time::Instant};
+use gd_enums::friend_requests;
+use ownership::IntoOwned;
+
+use crate::{entities::Entity, users::UserReference};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Builder, IntoOwned)]
+pub struct FriendRequest<'f> {
+    pub id: FriendRequestId,
+    pub user: UserReference<'f>,
+    #[builder(default)]
+    pub request_type: friend_requests::Type,
+    #[builder(default, into)]
+    pub content: Str<'f>,
+    pub created_at: Option<Instant>,
+    #[builder(default)]
+    pub read: bool,
+}
+
+impl fmt::Display for FriendRequest<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.content.fmt(formatter)
+    }
+}
+
+impl Entity for FriendRequest<'_> {
+    type Id = FriendRequestId;
+
+    fn id(&self) -> Self::Id {
+        self.id
+    }
+}
+
+impl FriendRequest<'_> {
+    pub const fn is_read(&self) -> bool {
+        self.read
+    }
+
+    pub const fn is_outgoing(&self) -> bool {
+        self.request_type.is_outgoing()
+    }
+}
@@ -0,0 +1,31 @@
+use std::fmt;
+
+use bon::Builder;
+use gd_core::types::id::{GauntletId, LevelId};
+use gd_enums::gauntlets;
+use ownership::IntoOwned;
+
+use crate::entities::Entity;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Builder, IntoOwned)]
+pub struct Gauntlet {
+    pub id: GauntletId,
+    #[builder(default)]
+    pub name: gauntlets::Name,
+    #[builder(default)]
+    pub level_ids: Vec<LevelId>,
+}
+
+impl fmt::Display for Gauntlet {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name.fmt(formatter)
+    }
+}
+
+impl Entity for Gauntlet {
+    type Id = GauntletId;
+
+    fn id(&self) -> Self::Id {
+        self.id
+    }
+}
@@ -0,0 +1,188 @@
+use std::fmt;
+
+use bon::Builder;
+use gd_core::types::{
+    id::{LevelId, TimelyId},
+    password::Password,
+    reward::{Reward, RewardCoins},
+    statistics::{Downloads, ObjectCount, Rating, Score},
+    str::Str,
+    time::{Duration, Instant, TimeSteps},
+    version::Version,
+};
+use gd_crypto::{base64, utf8, zip};
+use gd_enums::{
+    levels::{Difficulty, Length, RateType},
+    timely,
+};
+use gd_internals::thunk::{Processor, Thunk};
+use ownership::IntoOwned;
+use thiserror::Error;
+
+use crate::{entities::Entity, songs::SongReference, users::UserReference};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Builder, IntoOwned)]
+pub struct LevelReference<'l> {
+    pub id: LevelId,
+    #[builder(into)]
+    pub name: Str<'l>,
+}
+
+impl fmt::Display for LevelReference<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name.fmt(formatter)
+    }
+}
+
+impl Entity for LevelReference<'_> {
+    type Id = LevelId;
+
+    fn id(&self) -> Self::Id {
+        self.id
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("failed to decode level data")]
+pub enum DecodeError {
+    Base64(#[from] base64::Error),
+    Decompress(#[from] zip::DecompressError),
+    Utf8(#[from] utf8::Error),
+}
+
+#[derive(Debug, Error)]
+#[error("failed to encode level data")]
+pub struct EncodeError(#[from] pub zip::CompressError);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DataProcessor;
+
+impl Processor for DataProcessor {
+    type Unprocessed<'u> = Str<'u>;
+    type Processed<'p> = Str<'p>;
+
+    type ProcessError = DecodeError;
+    type UnprocessError = EncodeError;
+
+    fn process<'a>(
+        unprocessed: Self::Unprocessed<'a>,
+    ) -> Result<Self::Processed<'a>, Self::ProcessError> {
+        let compressed = base64::decode(unprocessed.as_ref())?;
+
+        let decompressed = zip::decompress(compressed)?;
+
+        let string = utf8::convert(decompressed)?;
+
+        Ok(Str::Owned(string))
+    }
+
+    fn unprocess<'a>(
+        processed: Self::Processed<'a>,
+    ) -> Result<Self::Unprocessed<'a>, Self::UnprocessError> {
+        let compressed = zip::compress(processed.as_ref())?;
+
+        Ok(Str::Owned(base64::encode(compressed)))
+    }
+}
+
+pub type Data<'d> = Thunk<'d, DataProcessor>;
+
+pub type Capacity = Vec<u16>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Builder, IntoOwned)]
+pub struct Level<'l> {
+    pub reference: LevelReference<'l>,
+    #[builder(default, into)]
+    pub description: Str<'l>,
+    pub creator: UserReference<'l>,
+    pub song: SongReference,
+    pub data: Option<Data<'l>>,
+    #[builder(default)]
+    pub version: Version,
+    #[builder(default)]
+    pub downloads: Downloads,
+    #[builder(default)]
+    pub game_version: Version,
+    #[builder(default)]
+    pub rating: Rating,
+    #[builder(default)]
+    pub length: Length,
+    #[builder(default)]
+    pub difficulty: Difficulty,
+    pub reward: Reward,
+    pub requested_reward: Reward,
+    #[builder(default)]
+    pub score: Score,
+    #[builder(default)]
+    pub rate_type: RateType,
+    #[builder(default)]
+    pub password: Password,
+    #[builder(default)]
+    pub original_id: LevelId,
+    #[builder(default)]
+    pub two_player: bool,
+    pub capacity: Option<Capacity>,
+    #[builder(default)]
+    pub coins: RewardCoins,
+    #[builder(default)]
+    pub verified_coins: bool,
+    #[builder(default)]
+    pub low_detail: bool,
+    #[builder(default)]
+    pub object_count: ObjectCount,
+    pub created_at: Option<Instant>,
+    pub updated_at: Option<Instant>,
+    #[builder(default)]
+    pub editor_time: Duration,
+    #[builder(default)]
+    pub copies_time: Duration,
+    #[builder(default)]
+    pub timely_type: timely::Type,
+    #[builder(default)]
+    pub timely_id: TimelyId,
+    #[builder(default)]
+    pub time_steps: TimeSteps,
+}
+
+impl fmt::Display for Level<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.reference.fmt(formatter)
+    }
+}
+
+impl Entity for Level<'_> {
+    type Id = LevelId;
+
+    fn id(&self) -> Self::Id {
+        self.reference.id
+    }
+}
+
+impl Level<'_> {
+    pub const fn is_platformer(&self) -> bool {
+        self.length.is_platformer()
+    }
+
+    pub const fn is_two_player(&self) -> bool {
+        self.two_player
+    }
+
+    pub const fn has_verified_coins(&self) -> bool {
+        self.verified_coins
+    }
+
+    pub const fn is_original(&self) -> bool {
+        self.original_id.get() == 0
+    }
+
+    pub const fn is_timely(&self) -> bool {
+        self.timely_type.is_timely()
+    }
+
+    pub fn objects(&mut self) -> Result<Option<&str>, DecodeError> {
+        self.data
+            .as_mut()
+            .map(|data| data.process().map(|processed| &**processed))
+            .transpose()
+    }
+}
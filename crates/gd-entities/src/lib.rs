@@ -1,9 +1,26 @@
 pub mod artists;
+pub mod comments;
 pub mod entities;
+pub mod friend_requests;
+pub mod gauntlets;
+pub mod levels;
+pub mod lists;
+pub mod map_packs;
+pub mod messages;
+pub mod songs;
 pub mod users;
 
 pub use artists::Artist;
+pub use comments::{Comment, CoreComment, LevelComment, UserComment};
 pub use entities::Entity;
+pub use friend_requests::FriendRequest;
+pub use gauntlets::Gauntlet;
+pub use levels::{Data, Level, LevelReference};
+pub use lists::LevelList;
+pub use map_packs::MapPack;
+pub use messages::Message;
+pub use songs::{Song, SongReference};
 pub use users::{
-    User, UserCosmetics, UserLeaderboard, UserReference, UserSocials, UserStates, UserStatistics,
+    User, UserCosmetics, UserLeaderboard, UserNotifications, UserReference, UserSocials,
+    UserStates, UserStatistics,
 };
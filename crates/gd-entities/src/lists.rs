@@ -0,0 +1,66 @@
+use std::fmt;
+
+use bon::Builder;
+use gd_core::types::{
+    id::{LevelId, ListId},
+    statistics::{Diamonds, Downloads, Levels, Rating},
+    str::Str,
+    time::Instant,
+    version::Version,
+};
+use gd_enums::levels::Difficulty;
+use ownership::IntoOwned;
+
+use crate::{entities::Entity, users::UserReference};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Builder, IntoOwned)]
+pub struct LevelList<'l> {
+    pub id: ListId,
+    #[builder(into)]
+    pub name: Str<'l>,
+    #[builder(default, into)]
+    pub description: Str<'l>,
+    pub creator: UserReference<'l>,
+    #[builder(default)]
+    pub level_ids: Vec<LevelId>,
+    #[builder(default)]
+    pub version: Version,
+    #[builder(default)]
+    pub difficulty: Difficulty,
+    #[builder(default)]
+    pub downloads: Downloads,
+    #[builder(default)]
+    pub rating: Rating,
+    #[builder(default)]
+    pub reward: Diamonds,
+    #[builder(default)]
+    pub reward_threshold: Levels,
+    #[builder(default)]
+    pub featured: bool,
+    pub created_at: Option<Instant>,
+    pub updated_at: Option<Instant>,
+}
+
+impl fmt::Display for LevelList<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name.fmt(formatter)
+    }
+}
+
+impl Entity for LevelList<'_> {
+    type Id = ListId;
+
+    fn id(&self) -> Self::Id {
+        self.id
+    }
+}
+
+impl LevelList<'_> {
+    pub const fn is_featured(&self) -> bool {
+        self.featured
+    }
+
+    pub const fn has_reward(&self) -> bool {
+        self.reward.get() > 0
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt;
+
+use bon::Builder;
+use gd_core::types::{
+    color::Color,
+    id::{LevelId, MapPackId},
+    reward::{RewardCoins, RewardStars},
+    str::Str,
+};
+use gd_enums::levels::Difficulty;
+use ownership::IntoOwned;
+
+use crate::entities::Entity;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Builder, IntoOwned)]
+pub struct MapPack<'m> {
+    pub id: MapPackId,
+    #[builder(into)]
+    pub name: Str<'m>,
+    #[builder(default)]
+    pub level_ids: Vec<LevelId>,
+    #[builder(default)]
+    pub stars: RewardStars,
+    #[builder(default)]
+    pub coins: RewardCoins,
+    #[builder(default)]
+    pub difficulty: Difficulty,
+    #[builder(default = Color::WHITE)]
+    pub color: Color,
+    #[builder(default = Color::WHITE)]
+    pub bar_color: Color,
+}
+
+impl fmt::Display for MapPack<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name.fmt(formatter)
+    }
+}
+
+impl Entity for MapPack<'_> {
+    type Id = MapPackId;
+
+    fn id(&self) -> Self::Id {
+        self.id
+    }
+}
@@ -0,0 +1,51 @@
+use std::fmt;
+
+use bon::Builder;
+use gd_core::types::{id::MessageId, str::Str, time::Instant};
+use gd_enums::messages;
+use ownership::IntoOwned;
+
+use crate::{entities::Entity, users::UserReference};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Builder, IntoOwned)]
+pub struct Message<'m> {
+    pub id: MessageId,
+    pub user: UserReference<'m>,
+    #[builder(default)]
+    pub message_type: messages::Type,
+    #[builder(default, into)]
+    pub subject: Str<'m>,
+    #[builder(into)]
+    pub content: Option<Str<'m>>,
+    pub created_at: Option<Instant>,
+    #[builder(default)]
+    pub read: bool,
+}
+
+impl fmt::Display for Message<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.subject.fmt(formatter)
+    }
+}
+
+impl Entity for Message<'_> {
+    type Id = MessageId;
+
+    fn id(&self) -> Self::Id {
+        self.id
+    }
+}
+
+impl Message<'_> {
+    pub const fn is_read(&self) -> bool {
+        self.read
+    }
+
+    pub const fn is_outgoing(&self) -> bool {
+        self.message_type.is_outgoing()
+    }
+
+    pub const fn has_content(&self) -> bool {
+        self.content.is_some()
+    }
+}
@@ -0,0 +1,54 @@
+use std::fmt;
+
+use bon::Builder;
+use gd_core::types::{id::SongId, str::Str};
+use ownership::IntoOwned;
+
+use crate::{artists::Artist, entities::Entity};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Builder, IntoOwned)]
+pub struct SongReference {
+    pub id: SongId,
+    #[builder(default)]
+    pub custom: bool,
+}
+
+impl SongReference {
+    pub const fn is_custom(&self) -> bool {
+        self.custom
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Builder, IntoOwned)]
+pub struct Song<'s> {
+    pub id: SongId,
+    #[builder(into)]
+    pub name: Str<'s>,
+    pub artist: Artist<'s>,
+    #[builder(default)]
+    pub size: f32,
+    pub url: Option<Str<'s>>,
+}
+
+impl Entity for Song<'_> {
+    type Id = SongId;
+
+    fn id(&self) -> Self::Id {
+        self.id
+    }
+}
+
+impl fmt::Display for Song<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name.fmt(formatter)
+    }
+}
+
+impl Song<'_> {
+    pub const fn reference(&self) -> SongReference {
+        SongReference {
+            id: self.id,
+            custom: true,
+        }
+    }
+}
@@ -12,7 +12,7 @@ use gd_core::types::{
     str::Str,
     time::Instant,
 };
-use gd_enums::{comments, icons};
+use gd_enums::{comments, friend_requests, friends, icons, messages};
 use ownership::IntoOwned;
 use serde::{Deserialize, Serialize};
 
@@ -115,28 +115,48 @@ pub struct UserCosmetics {
     Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Builder, Serialize, Deserialize, IntoOwned,
 )]
 pub struct UserStates {
+    #[builder(default)]
+    pub messages: messages::State,
+    #[builder(default)]
+    pub friend_requests: friend_requests::State,
     #[builder(default)]
     pub comments: comments::State,
-    // TODO: message, friend request, friend (?)
+    #[builder(default)]
+    pub friend: friends::State,
 }
 
 #[derive(
     Debug, Clone, PartialEq, Eq, Hash, Default, Builder, Serialize, Deserialize, IntoOwned,
 )]
 pub struct UserSocials<'u> {
-    youtube: Option<Str<'u>>,
-    x: Option<Str<'u>>,
-    twitch: Option<Str<'u>>,
-    discord: Option<Str<'u>>,
+    #[builder(into)]
+    pub youtube: Option<Str<'u>>,
+    #[builder(into)]
+    pub x: Option<Str<'u>>,
+    #[builder(into)]
+    pub twitch: Option<Str<'u>>,
+    #[builder(into)]
+    pub discord: Option<Str<'u>>,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Builder, Serialize, Deserialize, IntoOwned,
+)]
+pub struct UserNotifications {
+    #[builder(default)]
+    pub messages: u32,
+    #[builder(default)]
+    pub friend_requests: u32,
+    #[builder(default)]
+    pub friends: u32,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Builder, Serialize, Deserialize, IntoOwned)]
 pub struct UserLeaderboard {
-    record: Record,
+    pub record: Record,
     #[builder(default)]
-    coins: RewardCoins,
-    #[builder(default)]
-    recorded_at: Instant,
+    pub coins: RewardCoins,
+    pub recorded_at: Option<Instant>,
 }
 
 #[derive(
@@ -144,8 +164,7 @@ pub struct UserLeaderboard {
 )]
 pub struct User<'u> {
     pub reference: UserReference<'u>,
-    #[builder(default)]
-    pub banned: bool,
+    pub banned: Option<bool>,
     #[builder(default)]
     pub role_id: RoleId,
     pub statistics: Option<UserStatistics>,
@@ -154,6 +173,7 @@ pub struct User<'u> {
     pub socials: Option<UserSocials<'u>>,
     pub place: Option<Place>,
     pub leaderboard: Option<UserLeaderboard>,
+    pub notifications: Option<UserNotifications>,
 }
 
 impl fmt::Display for User<'_> {
@@ -172,6 +192,6 @@ impl Entity for User<'_> {
 
 impl User<'_> {
     pub const fn is_banned(&self) -> bool {
-        self.banned
+        matches!(self.banned, Some(true))
     }
 }
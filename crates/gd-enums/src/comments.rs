@@ -3,16 +3,33 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
 #[serde(rename_all = "snake_case")]
+#[repr(u8)]
 pub enum State {
     #[default]
-    OpenToAll,
-    OpenToFriends,
-    Closed,
+    OpenToAll = 0,
+    OpenToFriends = 1,
+    Closed = 2,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, IntoOwned)]
 #[serde(rename_all = "snake_case")]
+#[repr(u8)]
 pub enum Type {
-    User,
-    Level,
+    User = 1,
+    Level = 0,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum Sorting {
+    #[default]
+    Recent = 0,
+    MostLiked = 1,
+}
+
+impl_value!(
+    State => [OpenToAll, OpenToFriends, Closed],
+    Type => [User, Level],
+    Sorting => [Recent, MostLiked],
+);
@@ -0,0 +1,37 @@
+use ownership::IntoOwned;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum State {
+    #[default]
+    Open = 0,
+    Closed = 1,
+}
+
+impl State {
+    pub const fn is_closed(self) -> bool {
+        matches!(self, Self::Closed)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum Type {
+    #[default]
+    Incoming = 0,
+    Outgoing = 1,
+}
+
+impl Type {
+    pub const fn is_outgoing(self) -> bool {
+        matches!(self, Self::Outgoing)
+    }
+}
+
+impl_value!(
+    State => [Open, Closed],
+    Type => [Incoming, Outgoing],
+);
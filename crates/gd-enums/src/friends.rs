@@ -0,0 +1,15 @@
+use ownership::IntoOwned;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum State {
+    #[default]
+    None = 0,
+    Friend = 1,
+    Outgoing = 3,
+    Incoming = 4,
+}
+
+impl_value!(State => [None, Friend, Outgoing, Incoming]);
@@ -0,0 +1,207 @@
+use std::fmt;
+
+use ownership::IntoOwned;
+use serde::{Deserialize, Serialize};
+
+pub const UNKNOWN: &str = "Unknown";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum Name {
+    #[default]
+    Unknown = 0,
+    Fire = 1,
+    Ice = 2,
+    Poison = 3,
+    Shadow = 4,
+    Lava = 5,
+    Bonus = 6,
+    Chaos = 7,
+    Demon = 8,
+    Time = 9,
+    Crystal = 10,
+    Magic = 11,
+    Spike = 12,
+    Monster = 13,
+    Doom = 14,
+    Death = 15,
+    Forest = 16,
+    Rune = 17,
+    Force = 18,
+    Spooky = 19,
+    Dragon = 20,
+    Water = 21,
+    Haunted = 22,
+    Acid = 23,
+    Witch = 24,
+    Power = 25,
+    Potion = 26,
+    Snake = 27,
+    Toxic = 28,
+    Halloween = 29,
+    Treasure = 30,
+    Ghost = 31,
+    Spider = 32,
+    Gem = 33,
+    Inferno = 34,
+    Portal = 35,
+    Strange = 36,
+    Fantasy = 37,
+    Christmas = 38,
+    Surprise = 39,
+    Mystery = 40,
+    Cursed = 41,
+    Cyborg = 42,
+    Castle = 43,
+    Grave = 44,
+    Temple = 45,
+    World = 46,
+    Galaxy = 47,
+    Universe = 48,
+    Discord = 49,
+    Split = 50,
+    NcsI = 51,
+    NcsII = 52,
+    Space = 53,
+    Cosmos = 54,
+}
+
+impl Name {
+    pub const fn from_id(id: u64) -> Self {
+        if id > u8::MAX as u64 {
+            return Self::Unknown;
+        }
+
+        match Self::from_value(id as u8) {
+            Some(name) => name,
+            None => Self::Unknown,
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Unknown => UNKNOWN,
+            Self::Fire => "Fire",
+            Self::Ice => "Ice",
+            Self::Poison => "Poison",
+            Self::Shadow => "Shadow",
+            Self::Lava => "Lava",
+            Self::Bonus => "Bonus",
+            Self::Chaos => "Chaos",
+            Self::Demon => "Demon",
+            Self::Time => "Time",
+            Self::Crystal => "Crystal",
+            Self::Magic => "Magic",
+            Self::Spike => "Spike",
+            Self::Monster => "Monster",
+            Self::Doom => "Doom",
+            Self::Death => "Death",
+            Self::Forest => "Forest",
+            Self::Rune => "Rune",
+            Self::Force => "Force",
+            Self::Spooky => "Spooky",
+            Self::Dragon => "Dragon",
+            Self::Water => "Water",
+            Self::Haunted => "Haunted",
+            Self::Acid => "Acid",
+            Self::Witch => "Witch",
+            Self::Power => "Power",
+            Self::Potion => "Potion",
+            Self::Snake => "Snake",
+            Self::Toxic => "Toxic",
+            Self::Halloween => "Halloween",
+            Self::Treasure => "Treasure",
+            Self::Ghost => "Ghost",
+            Self::Spider => "Spider",
+            Self::Gem => "Gem",
+            Self::Inferno => "Inferno",
+            Self::Portal => "Portal",
+            Self::Strange => "Strange",
+            Self::Fantasy => "Fantasy",
+            Self::Christmas => "Christmas",
+            Self::Surprise => "Surprise",
+            Self::Mystery => "Mystery",
+            Self::Cursed => "Cursed",
+            Self::Cyborg => "Cyborg",
+            Self::Castle => "Castle",
+            Self::Grave => "Grave",
+            Self::Temple => "Temple",
+            Self::World => "World",
+            Self::Galaxy => "Galaxy",
+            Self::Universe => "Universe",
+            Self::Discord => "Discord",
+            Self::Split => "Split",
+            Self::NcsI => "NCS I",
+            Self::NcsII => "NCS II",
+            Self::Space => "Space",
+            Self::Cosmos => "Cosmos",
+        }
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(formatter)
+    }
+}
+
+impl_value!(
+    Name => [
+        Unknown,
+        Fire,
+        Ice,
+        Poison,
+        Shadow,
+        Lava,
+        Bonus,
+        Chaos,
+        Demon,
+        Time,
+        Crystal,
+        Magic,
+        Spike,
+        Monster,
+        Doom,
+        Death,
+        Forest,
+        Rune,
+        Force,
+        Spooky,
+        Dragon,
+        Water,
+        Haunted,
+        Acid,
+        Witch,
+        Power,
+        Potion,
+        Snake,
+        Toxic,
+        Halloween,
+        Treasure,
+        Ghost,
+        Spider,
+        Gem,
+        Inferno,
+        Portal,
+        Strange,
+        Fantasy,
+        Christmas,
+        Surprise,
+        Mystery,
+        Cursed,
+        Cyborg,
+        Castle,
+        Grave,
+        Temple,
+        World,
+        Galaxy,
+        Universe,
+        Discord,
+        Split,
+        NcsI,
+        NcsII,
+        Space,
+        Cosmos,
+    ],
+);
@@ -3,15 +3,18 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
 #[serde(rename_all = "snake_case")]
+#[repr(u8)]
 pub enum Type {
     #[default]
-    Cube,
-    Ship,
-    Ball,
-    Ufo,
-    Wave,
-    Robot,
-    Spider,
-    Swing,
-    Jetpack,
+    Cube = 0,
+    Ship = 1,
+    Ball = 2,
+    Ufo = 3,
+    Wave = 4,
+    Robot = 5,
+    Spider = 6,
+    Swing = 7,
+    Jetpack = 8,
 }
+
+impl_value!(Type => [Cube, Ship, Ball, Ufo, Wave, Robot, Spider, Swing, Jetpack]);
@@ -0,0 +1,44 @@
+use ownership::IntoOwned;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum LeaderboardKind {
+    #[default]
+    Top = 0,
+    Creators = 1,
+    Friends = 2,
+    Relative = 3,
+}
+
+impl LeaderboardKind {
+    pub const fn is_personal(self) -> bool {
+        matches!(self, Self::Friends | Self::Relative)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum LevelLeaderboardKind {
+    Friends = 0,
+    #[default]
+    Top = 1,
+    Weekly = 2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum PlatformerMode {
+    #[default]
+    Time = 0,
+    Points = 1,
+}
+
+impl_value!(
+    LeaderboardKind => [Top, Creators, Friends, Relative],
+    LevelLeaderboardKind => [Friends, Top, Weekly],
+    PlatformerMode => [Time, Points],
+);
@@ -0,0 +1,96 @@
+use ownership::IntoOwned;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum Difficulty {
+    #[default]
+    NotAvailable = 0,
+    Auto = 1,
+    Easy = 2,
+    Normal = 3,
+    Hard = 4,
+    Harder = 5,
+    Insane = 6,
+    EasyDemon = 7,
+    MediumDemon = 8,
+    HardDemon = 9,
+    InsaneDemon = 10,
+    ExtremeDemon = 11,
+}
+
+impl Difficulty {
+    pub const fn is_demon(self) -> bool {
+        matches!(
+            self,
+            Self::EasyDemon
+                | Self::MediumDemon
+                | Self::HardDemon
+                | Self::InsaneDemon
+                | Self::ExtremeDemon
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum Length {
+    #[default]
+    Tiny = 0,
+    Short = 1,
+    Medium = 2,
+    Long = 3,
+    ExtraLong = 4,
+    Platformer = 5,
+}
+
+impl Length {
+    pub const fn is_platformer(self) -> bool {
+        matches!(self, Self::Platformer)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum Visibility {
+    #[default]
+    Listed = 0,
+    FriendsOnly = 1,
+    Unlisted = 2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum RateType {
+    #[default]
+    NotRated = 0,
+    Rated = 1,
+    Featured = 2,
+    Epic = 3,
+    Legendary = 4,
+    Mythic = 5,
+}
+
+impl_value!(
+    Difficulty => [
+        NotAvailable,
+        Auto,
+        Easy,
+        Normal,
+        Hard,
+        Harder,
+        Insane,
+        EasyDemon,
+        MediumDemon,
+        HardDemon,
+        InsaneDemon,
+        ExtremeDemon,
+    ],
+    Length => [Tiny, Short, Medium, Long, ExtraLong, Platformer],
+    Visibility => [Listed, FriendsOnly, Unlisted],
+    RateType => [NotRated, Rated, Featured, Epic, Legendary, Mythic],
+);
@@ -1,2 +1,16 @@
+#[macro_use]
+pub mod macros;
 pub mod comments;
+pub mod friend_requests;
+pub mod friends;
+pub mod gauntlets;
 pub mod icons;
+pub mod leaderboards;
+pub mod levels;
+pub mod likes;
+pub mod messages;
+pub mod quests;
+pub mod rewards;
+pub mod searches;
+pub mod timely;
+pub mod values;
@@ -0,0 +1,15 @@
+use ownership::IntoOwned;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum Type {
+    #[default]
+    Level = 1,
+    LevelComment = 2,
+    UserComment = 3,
+    List = 4,
+}
+
+impl_value!(Type => [Level, LevelComment, UserComment, List]);
@@ -0,0 +1,36 @@
+#[macro_export]
+macro_rules! impl_value {
+    ($($name: ident => [$($variant: ident),+ $(,)?]),+ $(,)?) => {
+        $(
+            impl $name {
+                pub const fn from_value(value: u8) -> Option<Self> {
+                    $(
+                        if value == Self::$variant as u8 {
+                            return Some(Self::$variant);
+                        }
+                    )+
+
+                    None
+                }
+
+                pub const fn value(self) -> u8 {
+                    self as u8
+                }
+            }
+
+            impl TryFrom<u8> for $name {
+                type Error = $crate::values::ValueError;
+
+                fn try_from(value: u8) -> Result<Self, Self::Error> {
+                    Self::from_value(value).ok_or($crate::values::ValueError::new(value))
+                }
+            }
+
+            impl From<$name> for u8 {
+                fn from(item: $name) -> Self {
+                    item.value()
+                }
+            }
+        )+
+    };
+}
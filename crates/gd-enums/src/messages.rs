@@ -0,0 +1,32 @@
+use ownership::IntoOwned;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum State {
+    #[default]
+    OpenToAll = 0,
+    OpenToFriends = 1,
+    Closed = 2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum Type {
+    #[default]
+    Incoming = 0,
+    Outgoing = 1,
+}
+
+impl Type {
+    pub const fn is_outgoing(self) -> bool {
+        matches!(self, Self::Outgoing)
+    }
+}
+
+impl_value!(
+    State => [OpenToAll, OpenToFriends, Closed],
+    Type => [Incoming, Outgoing],
+);
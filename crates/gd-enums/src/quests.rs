@@ -0,0 +1,14 @@
+use ownership::IntoOwned;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum Type {
+    #[default]
+    Orbs = 1,
+    Coins = 2,
+    Stars = 3,
+}
+
+impl_value!(Type => [Orbs, Coins, Stars]);
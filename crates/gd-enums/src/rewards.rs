@@ -0,0 +1,35 @@
+use ownership::IntoOwned;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum Chest {
+    #[default]
+    Small = 1,
+    Large = 2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum Item {
+    #[default]
+    FireShard = 1,
+    IceShard = 2,
+    PoisonShard = 3,
+    ShadowShard = 4,
+    LavaShard = 5,
+    DemonKey = 6,
+}
+
+impl Item {
+    pub const fn is_shard(self) -> bool {
+        !matches!(self, Self::DemonKey)
+    }
+}
+
+impl_value!(
+    Chest => [Small, Large],
+    Item => [FireShard, IceShard, PoisonShard, ShadowShard, LavaShard, DemonKey],
+);
@@ -0,0 +1,38 @@
+use ownership::IntoOwned;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum Type {
+    #[default]
+    Regular = 0,
+    MostDownloaded = 1,
+    MostLiked = 2,
+    Trending = 3,
+    Recent = 4,
+    User = 5,
+    Featured = 6,
+    Magic = 7,
+    Awarded = 11,
+    Followed = 12,
+    Friends = 13,
+    HallOfFame = 16,
+}
+
+impl_value!(
+    Type => [
+        Regular,
+        MostDownloaded,
+        MostLiked,
+        Trending,
+        Recent,
+        User,
+        Featured,
+        Magic,
+        Awarded,
+        Followed,
+        Friends,
+        HallOfFame,
+    ],
+);
@@ -0,0 +1,21 @@
+use ownership::IntoOwned;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, IntoOwned)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum Type {
+    #[default]
+    NotTimely = 0,
+    Daily = 1,
+    Weekly = 2,
+    Event = 3,
+}
+
+impl Type {
+    pub const fn is_timely(self) -> bool {
+        !matches!(self, Self::NotTimely)
+    }
+}
+
+impl_value!(Type => [NotTimely, Daily, Weekly, Event]);
@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Error)]
+#[error("unknown value `{value}`")]
+pub struct ValueError {
+    value: u8,
+}
+
+impl ValueError {
+    pub const fn new(value: u8) -> Self {
+        Self { value }
+    }
+
+    pub const fn get(self) -> u8 {
+        self.value
+    }
+}
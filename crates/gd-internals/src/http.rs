@@ -2,9 +2,10 @@ use std::sync::Arc;
 
 use bon::Builder;
 use reqwest::{
-    Client, Url,
+    Client, StatusCode, Url,
     header::{COOKIE, HeaderMap, HeaderValue},
 };
+use serde::Serialize;
 use thiserror::Error;
 
 pub const CREATE_ERROR: &str = "failed to create the client";
@@ -17,6 +18,18 @@ pub struct CreateError {
     pub error: reqwest::Error,
 }
 
+pub const REQUEST_ERROR: &str = "failed to send the request";
+
+pub const RATE_LIMITED: &str = "rate limited";
+
+#[derive(Debug, Error)]
+pub enum RequestError {
+    #[error("{REQUEST_ERROR}")]
+    Send(#[from] reqwest::Error),
+    #[error("{RATE_LIMITED}")]
+    RateLimited,
+}
+
 #[derive(Debug, Clone, Builder)]
 pub struct Http {
     pub client: Client,
@@ -60,4 +73,36 @@ impl Http {
 
         Ok(Self::default_with(client))
     }
+
+    pub fn url(&self, endpoint: &str) -> Url {
+        let mut url = self.base_url.as_ref().clone();
+
+        url.path_segments_mut()
+            .expect("base url must be able to have segments")
+            .pop_if_empty()
+            .extend(endpoint.split('/'));
+
+        url
+    }
+
+    pub async fn post<F: Serialize + ?Sized>(
+        &self,
+        endpoint: &str,
+        form: &F,
+    ) -> Result<String, RequestError> {
+        let response = self
+            .client
+            .post(self.url(endpoint))
+            .form(form)
+            .send()
+            .await?;
+
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            return Err(RequestError::RateLimited);
+        }
+
+        let text = response.error_for_status()?.text().await?;
+
+        Ok(text)
+    }
 }
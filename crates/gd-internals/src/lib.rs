@@ -1,4 +1,5 @@
 pub mod http;
+pub mod robtop;
 pub mod session;
 pub mod thunk;
 
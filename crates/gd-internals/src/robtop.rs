@@ -0,0 +1,114 @@
+use std::{collections::HashMap, str::FromStr};
+
+use thiserror::Error;
+
+pub const FALSE: &str = "0";
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Error)]
+#[error("missing value for key `{key}`")]
+pub struct MissingError {
+    pub key: String,
+}
+
+impl MissingError {
+    pub fn new<K: Into<String>>(key: K) -> Self {
+        Self { key: key.into() }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Error)]
+#[error("invalid value `{value}` for key `{key}`")]
+pub struct InvalidError {
+    pub key: String,
+    pub value: String,
+}
+
+impl InvalidError {
+    pub fn new<K: Into<String>, V: Into<String>>(key: K, value: V) -> Self {
+        Self {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Error)]
+#[error(transparent)]
+pub enum Error {
+    Missing(#[from] MissingError),
+    Invalid(#[from] InvalidError),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Map<'m> {
+    pairs: HashMap<&'m str, &'m str>,
+}
+
+impl<'m> Map<'m> {
+    pub fn parse(string: &'m str, delimiter: &str) -> Self {
+        let mut pairs = HashMap::new();
+
+        let mut iterator = string.split(delimiter);
+
+        while let Some(key) = iterator.next() {
+            let value = iterator.next().unwrap_or_default();
+
+            pairs.insert(key, value);
+        }
+
+        Self { pairs }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&'m str> {
+        self.pairs
+            .get(key)
+            .copied()
+            .filter(|value| !value.is_empty())
+    }
+
+    pub fn require(&self, key: &str) -> Result<&'m str, MissingError> {
+        self.get(key).ok_or_else(|| MissingError::new(key))
+    }
+
+    pub fn parse_optional<T: FromStr>(&self, key: &str) -> Result<Option<T>, Error> {
+        self.get(key)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| InvalidError::new(key, value).into())
+            })
+            .transpose()
+    }
+
+    pub fn parse_or_default<T: FromStr + Default>(&self, key: &str) -> Result<T, Error> {
+        self.parse_optional(key)
+            .map(|option| option.unwrap_or_default())
+    }
+
+    pub fn parse_required<T: FromStr>(&self, key: &str) -> Result<T, Error> {
+        let value = self.require(key)?;
+
+        let parsed = value.parse().map_err(|_| InvalidError::new(key, value))?;
+
+        Ok(parsed)
+    }
+
+    pub fn parse_bool(&self, key: &str) -> bool {
+        self.get(key).is_some_and(|value| value != FALSE)
+    }
+
+    pub fn parse_list<T: FromStr>(&self, key: &str, delimiter: &str) -> Result<Vec<T>, Error> {
+        let Some(string) = self.get(key) else {
+            return Ok(Vec::new());
+        };
+
+        string
+            .split(delimiter)
+            .filter(|item| !item.is_empty())
+            .map(|item| {
+                item.parse()
+                    .map_err(|_| InvalidError::new(key, item).into())
+            })
+            .collect()
+    }
+}
@@ -1,9 +1,12 @@
 use std::{
+    borrow::Cow,
     error::Error,
     hash::{Hash, Hasher},
     mem::{discriminant, replace},
 };
 
+use ownership::IntoOwned;
+
 pub trait Empty {
     fn empty() -> Self;
 }
@@ -14,6 +17,10 @@ pub fn take<T: Empty>(value: &mut T) -> T {
 
 pub trait DefaultIsEmpty: Default {}
 
+impl DefaultIsEmpty for String {}
+impl DefaultIsEmpty for Cow<'_, str> {}
+impl<T> DefaultIsEmpty for Vec<T> {}
+
 impl<T: DefaultIsEmpty> Empty for T {
     fn empty() -> Self {
         Self::default()
@@ -70,6 +77,21 @@ where
     }
 }
 
+impl<P: Processor + 'static> IntoOwned for Thunk<'_, P>
+where
+    for<'o> P::Unprocessed<'o>: IntoOwned<Owned = P::Unprocessed<'static>>,
+    for<'o> P::Processed<'o>: IntoOwned<Owned = P::Processed<'static>>,
+{
+    type Owned = Thunk<'static, P>;
+
+    fn into_owned(self) -> Self::Owned {
+        match self {
+            Self::Unprocessed(unprocessed) => Thunk::Unprocessed(unprocessed.into_owned()),
+            Self::Processed(processed) => Thunk::Processed(processed.into_owned()),
+        }
+    }
+}
+
 pub trait Processor {
     type Unprocessed<'u>;
     type Processed<'p>;